@@ -0,0 +1,54 @@
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use personnummer::{bulk, Personnummer};
+
+use std::hint::black_box;
+
+/// Returns a mix of valid and invalid twelve digit numbers.
+fn numbers() -> Vec<[u8; 12]> {
+    (0..10_000u64)
+        .map(|i| {
+            let month = i % 12 + 1;
+            let day = i % 28 + 1;
+            let serial = i % 999 + 1;
+            let pnr = format!(
+                "19{:02}{:02}{:02}{:03}{}",
+                i % 100,
+                month,
+                day,
+                serial,
+                i % 10
+            );
+
+            pnr.as_bytes().try_into().unwrap()
+        })
+        .collect()
+}
+
+fn bench_validate(c: &mut Criterion) {
+    let numbers = numbers();
+    let strings = numbers
+        .iter()
+        .map(|n| String::from_utf8(n.to_vec()).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("validate");
+    group.throughput(Throughput::Elements(numbers.len() as u64));
+
+    group.bench_function("bulk", |b| {
+        b.iter(|| bulk::count_valid(black_box(&numbers)))
+    });
+
+    group.bench_function("personnummer", |b| {
+        b.iter(|| {
+            black_box(&strings)
+                .iter()
+                .filter(|s| Personnummer::new(s).map(|p| p.valid()).unwrap_or(false))
+                .count()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_validate);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+const assert = require("node:assert");
+const test = require("node:test");
+
+const personnummer = require("../index.js");
+
+test("validate", () => {
+  assert.strictEqual(personnummer.validate("900101-0017"), true);
+  assert.strictEqual(personnummer.validate("900101-0018"), false);
+});
+
+test("parse", () => {
+  const parsed = personnummer.parse("800161-3291");
+
+  assert.strictEqual(parsed.long, "19800161-3291");
+  assert.strictEqual(parsed.short, "800161-3291");
+  assert.strictEqual(parsed.gender, "male");
+  assert.strictEqual(parsed.coordination, true);
+});
+
+test("format", () => {
+  assert.strictEqual(personnummer.formatLong("9001010017"), "19900101-0017");
+  assert.strictEqual(personnummer.formatShort("19900101-0017"), "900101-0017");
+});
+
+test("invalid input throws", () => {
+  assert.throws(() => personnummer.age("900101-0018"), /Invalid checksum/);
+});
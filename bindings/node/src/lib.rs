@@ -0,0 +1,61 @@
+//! Node.js bindings for the personnummer crate through napi-rs. All functions take the personal
+//! identity number as a string and throw an `Error` with the parse error message for invalid input.
+
+use napi::{Error, Result};
+use napi_derive::napi;
+
+use personnummer::Personnummer;
+
+/// [Parsed] is a parsed and valid personal identity number returned to JavaScript.
+#[napi(object)]
+pub struct Parsed {
+    pub long: String,
+    pub short: String,
+    pub age: u32,
+    pub gender: String,
+    pub coordination: bool,
+}
+
+/// Parse a personal identity number, throws if it isn't valid.
+#[napi]
+pub fn parse(pnr: String) -> Result<Parsed> {
+    let pnr = strict(&pnr)?;
+    let formatted = pnr.format();
+
+    Ok(Parsed {
+        long: formatted.long(),
+        short: formatted.short(),
+        age: pnr.get_age() as u32,
+        gender: pnr.gender().to_string(),
+        coordination: pnr.is_coordination_number(),
+    })
+}
+
+/// Check if the input is a valid personal identity number.
+#[napi]
+pub fn validate(pnr: String) -> bool {
+    personnummer::quick_valid(&pnr)
+}
+
+/// Returns the long format, e.g. `19900101-0017`.
+#[napi]
+pub fn format_long(pnr: String) -> Result<String> {
+    Ok(strict(&pnr)?.format().long())
+}
+
+/// Returns the short format, e.g. `900101-0017`.
+#[napi]
+pub fn format_short(pnr: String) -> Result<String> {
+    Ok(strict(&pnr)?.format().short())
+}
+
+/// Returns the current age of the person.
+#[napi]
+pub fn age(pnr: String) -> Result<u32> {
+    Ok(strict(&pnr)?.get_age() as u32)
+}
+
+/// Parse the personal identity number and map the error to a JavaScript error.
+fn strict(pnr: &str) -> Result<Personnummer> {
+    personnummer::parse_valid(pnr).map_err(|e| Error::from_reason(e.to_string()))
+}
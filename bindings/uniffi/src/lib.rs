@@ -0,0 +1,121 @@
+//! Kotlin and Swift bindings for the personnummer crate through UniFFI. The functions are the same
+//! as the Node.js bindings and throw a `ParseException` (Kotlin) or `ParseError` (Swift) with the
+//! parse error message for invalid input.
+//!
+//! Build the library for each target and generate the bindings from it.
+//!
+//! ```sh
+//! cargo build --release
+//! cargo run --bin uniffi-bindgen -- generate --library target/release/libpersonnummer_uniffi.so \
+//!     --language kotlin --language swift --out-dir out
+//! ```
+
+use std::fmt;
+
+use personnummer::{Personnummer, PersonnummerError};
+
+uniffi::setup_scaffolding!();
+
+/// [Gender] of the person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum Gender {
+    Female,
+    Male,
+}
+
+/// [Parsed] is a parsed and valid personal identity number.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Record)]
+pub struct Parsed {
+    pub long: String,
+    pub short: String,
+    pub age: u32,
+    pub gender: Gender,
+    pub coordination: bool,
+}
+
+/// [ParseError] is thrown when the input isn't a valid personal identity number.
+#[derive(Debug, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum ParseError {
+    Invalid(PersonnummerError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Invalid(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a personal identity number, throws if it isn't valid.
+#[uniffi::export]
+pub fn parse(pnr: &str) -> Result<Parsed, ParseError> {
+    let pnr = strict(pnr)?;
+    let formatted = pnr.format();
+
+    Ok(Parsed {
+        long: formatted.long(),
+        short: formatted.short(),
+        age: pnr.get_age() as u32,
+        gender: if pnr.is_female() {
+            Gender::Female
+        } else {
+            Gender::Male
+        },
+        coordination: pnr.is_coordination_number(),
+    })
+}
+
+/// Check if the input is a valid personal identity number.
+#[uniffi::export]
+pub fn validate(pnr: &str) -> bool {
+    personnummer::quick_valid(pnr)
+}
+
+/// Returns the long format, e.g. `19900101-0017`.
+#[uniffi::export]
+pub fn format_long(pnr: &str) -> Result<String, ParseError> {
+    Ok(strict(pnr)?.format().long())
+}
+
+/// Returns the short format, e.g. `900101-0017`.
+#[uniffi::export]
+pub fn format_short(pnr: &str) -> Result<String, ParseError> {
+    Ok(strict(pnr)?.format().short())
+}
+
+/// Returns the current age of the person.
+#[uniffi::export]
+pub fn age(pnr: &str) -> Result<u32, ParseError> {
+    Ok(strict(pnr)?.get_age() as u32)
+}
+
+/// Parse the personal identity number and map the error to a [ParseError].
+fn strict(pnr: &str) -> Result<Personnummer, ParseError> {
+    personnummer::parse_valid(pnr).map_err(ParseError::Invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let parsed = parse("800161-3291").unwrap();
+
+        assert_eq!(parsed.long, "19800161-3291");
+        assert_eq!(parsed.short, "800161-3291");
+        assert_eq!(parsed.gender, Gender::Male);
+        assert!(parsed.coordination);
+
+        assert!(validate("900101-0017"));
+        assert!(!validate("900101-0018"));
+        assert_eq!(
+            format_short("19900101-0018").unwrap_err().to_string(),
+            "Invalid checksum, expected control digit 7 but got 8"
+        );
+    }
+}
@@ -0,0 +1,33 @@
+// Validate and format personal identity numbers from C.
+//
+//   cargo rustc --lib --release --features ffi --crate-type staticlib
+//   cc -Iinclude -o validate examples/c/validate.c target/release/libpersonnummer.a -lm
+//   ./validate 9001010017
+
+#include <stdio.h>
+
+#include "personnummer.h"
+
+int main(int argc, char **argv) {
+  if (argc != 2) {
+    fprintf(stderr, "usage: %s <personnummer>\n", argv[0]);
+    return 2;
+  }
+
+  char long_format[PNR_LONG_LEN];
+  uint32_t age;
+
+  PnrError err = pnr_format_long(argv[1], long_format, sizeof(long_format));
+  if (err == PNR_ERROR_OK) {
+    err = pnr_age(argv[1], &age);
+  }
+
+  if (err != PNR_ERROR_OK) {
+    fprintf(stderr, "%s: %s\n", argv[1], pnr_error_message(err));
+    return 1;
+  }
+
+  printf("%s is %u years old\n", long_format, age);
+
+  return 0;
+}
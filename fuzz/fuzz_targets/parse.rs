@@ -0,0 +1,28 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use personnummer::{parse_valid, scan, Locale, Personnummer};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(fixed) = data.try_into() {
+        let _ = personnummer::bulk::is_valid(fixed);
+    }
+
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    match Personnummer::new(input) {
+        Ok(pnr) => {
+            let _ = pnr.valid();
+            let _ = pnr.format();
+            let _ = pnr.suggest_corrections();
+        }
+        Err(e) => {
+            let _ = e.message(Locale::Sv);
+        }
+    }
+
+    let _ = parse_valid(input);
+    let _ = scan::redact(input, &scan::RedactStyle::Full);
+});
@@ -0,0 +1,94 @@
+#ifndef PERSONNUMMER_H
+#define PERSONNUMMER_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The buffer size needed for the long format including the NUL terminator.
+#define PNR_LONG_LEN 14
+
+// The buffer size needed for the short format including the NUL terminator.
+#define PNR_SHORT_LEN 12
+
+// [PnrError] is the result of the C functions, see [PersonnummerError] for the parse errors.
+typedef enum PnrError {
+  // No error.
+  PNR_ERROR_OK = 0,
+  // A pointer argument is NULL.
+  PNR_ERROR_NULL_POINTER,
+  // The input isn't valid UTF-8.
+  PNR_ERROR_INVALID_UTF8,
+  // The output buffer is too small.
+  PNR_ERROR_BUFFER_TOO_SMALL,
+  PNR_ERROR_INVALID_INPUT,
+  PNR_ERROR_WRONG_LENGTH,
+  PNR_ERROR_INVALID_SEPARATOR,
+  PNR_ERROR_NON_DIGIT,
+  PNR_ERROR_INVALID_MONTH,
+  PNR_ERROR_INVALID_DAY,
+  PNR_ERROR_MISSING_CONTROL_DIGIT,
+  PNR_ERROR_INVALID_SERIAL,
+  PNR_ERROR_INVALID_CHECKSUM,
+  PNR_ERROR_FUTURE_BIRTH_DATE,
+  PNR_ERROR_INVALID_COORDINATION_DAY,
+} PnrError;
+
+#ifdef __cplusplus
+extern "C" {
+#endif  // __cplusplus
+
+// Check if the input is a valid personal identity number. Returns false for NULL and input that
+// isn't valid UTF-8.
+//
+// # Safety
+//
+// `pnr` must be NULL or point to a NUL terminated string.
+bool pnr_valid(const char *pnr);
+
+// Validate the input and return why it isn't a valid personal identity number, or
+// `PNR_ERROR_OK` if it's valid.
+//
+// # Safety
+//
+// `pnr` must be NULL or point to a NUL terminated string.
+enum PnrError pnr_validate(const char *pnr);
+
+// Write the long format, e.g. `19900101-0017`, as a NUL terminated string to `out`. The buffer
+// must be at least `PNR_LONG_LEN` bytes.
+//
+// # Safety
+//
+// `pnr` must be NULL or point to a NUL terminated string and `out` must be NULL or point to at
+// least `out_len` writable bytes.
+enum PnrError pnr_format_long(const char *pnr, char *out, size_t out_len);
+
+// Write the short format, e.g. `900101-0017`, as a NUL terminated string to `out`. The buffer
+// must be at least `PNR_SHORT_LEN` bytes.
+//
+// # Safety
+//
+// `pnr` must be NULL or point to a NUL terminated string and `out` must be NULL or point to at
+// least `out_len` writable bytes.
+enum PnrError pnr_format_short(const char *pnr, char *out, size_t out_len);
+
+// Write the current age of the person to `age`.
+//
+// # Safety
+//
+// `pnr` must be NULL or point to a NUL terminated string and `age` must be NULL or point to a
+// writable `uint32_t`.
+enum PnrError pnr_age(const char *pnr, uint32_t *age);
+
+// Returns a static NUL terminated English description of the error, or `Unknown error` if the
+// value isn't a `PnrError`.
+const char *pnr_error_message(int error);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PERSONNUMMER_H */
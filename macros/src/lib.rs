@@ -0,0 +1,124 @@
+//! Procedural macros for the `personnummer` crate, use them through the `macros` feature of
+//! `personnummer` instead of depending on this crate directly.
+
+use proc_macro::{Literal, TokenStream, TokenTree};
+
+/// [Parts] are the digits and divider of a personal identity number literal, in the same form as
+/// they're passed to `Personnummer::__from_macro()`.
+#[derive(Debug, PartialEq, Eq)]
+struct Parts {
+    digits: String,
+    divider: char,
+}
+
+/// Create a `Personnummer` from a string literal that is validated at compile time. The literal
+/// must include the century, e.g. `pnr!("19900101-0017")` or `pnr!("199001010017")`, since the
+/// century of a short format depends on the current date. Invalid literals fail to compile.
+#[proc_macro]
+pub fn pnr(input: TokenStream) -> TokenStream {
+    let result = literal(input).and_then(|value| Ok((parse(&value)?, value)));
+
+    let output = match result {
+        Ok((p, value)) => format!(
+            "{{ \
+                const _: () = ::core::assert!(\
+                    ::personnummer::bulk::is_valid(b\"{digits}\"), \
+                    {message:?}\
+                ); \
+                ::personnummer::Personnummer::__from_macro({digits}, {divider:?}) \
+            }}",
+            digits = p.digits,
+            message = format!("invalid personal identity number '{}'", value),
+            divider = p.divider,
+        ),
+        Err(e) => format!("::core::compile_error!({:?})", e),
+    };
+
+    output.parse().expect("generated code is valid")
+}
+
+/// Returns the value of a single string literal.
+fn literal(input: TokenStream) -> Result<String, String> {
+    let mut tokens = input.into_iter();
+
+    let value = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(lit)), None) => unquote(&lit),
+        (Some(TokenTree::Group(group)), None) => return literal(group.stream()),
+        _ => None,
+    };
+
+    value.ok_or_else(|| "pnr! expects a single string literal".to_string())
+}
+
+/// Returns the content of a plain string literal without escapes.
+fn unquote(lit: &Literal) -> Option<String> {
+    let lit = lit.to_string();
+    let value = lit.strip_prefix('"')?.strip_suffix('"')?;
+
+    (!value.contains('\\')).then(|| value.to_string())
+}
+
+/// Split a personal identity number with century into its digits and divider. The digits are
+/// validated by the generated code with `personnummer::bulk::is_valid()` so the rules only live in
+/// the library.
+fn parse(value: &str) -> Result<Parts, String> {
+    let bytes = value.as_bytes();
+
+    let (digits, divider) = match bytes.len() {
+        12 => (value.to_string(), '\0'),
+        13 if matches!(bytes[8], b'-' | b'+') => {
+            ([&value[..8], &value[9..]].concat(), bytes[8] as char)
+        }
+        10 | 11 => {
+            return Err(format!(
+                "personal identity number '{}' must include the century",
+                value
+            ))
+        }
+        _ => return Err(format!("invalid personal identity number '{}'", value)),
+    };
+
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("invalid personal identity number '{}'", value));
+    }
+
+    Ok(Parts { digits, divider })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("19900101-0017"),
+            Ok(Parts {
+                digits: "199001010017".to_string(),
+                divider: '-',
+            })
+        );
+        assert_eq!(parse("198001613291").unwrap().divider, '\0');
+        assert_eq!(parse("19130401+2931").unwrap().divider, '+');
+        // The rules are checked by the generated code, not when parsing the literal.
+        assert!(parse("19900101-0018").is_ok());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = vec![
+            ("900101-0017", "must include the century"),
+            ("9001010017", "must include the century"),
+            ("19900101_0017", "invalid personal identity number"),
+            ("1990010100a7", "invalid personal identity number"),
+            ("199001-010017", "invalid personal identity number"),
+            ("", "invalid personal identity number"),
+        ];
+
+        for (input, error) in cases {
+            let result = parse(input).unwrap_err();
+
+            assert!(result.contains(error), "{}: {}", input, result);
+        }
+    }
+}
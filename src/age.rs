@@ -0,0 +1,322 @@
+#[cfg(feature = "clock")]
+use crate::today;
+use crate::{date::days_in_month, Date, Personnummer};
+
+/// The age of majority in Sweden.
+const AGE_OF_MAJORITY: u32 = 18;
+
+/// [Age] is the exact age of a person in whole years, months and days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Age {
+    pub years: u32,
+    pub months: u32,
+    pub days: u32,
+}
+
+/// [LeapDayPolicy] decides when a month is passed if the day of birth doesn't exist in the month.
+/// This is most notable for people born on a leap day since it decides when they turn a year
+/// older in non leap years, but also applies to e.g. the 31st in months with 30 days.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeapDayPolicy {
+    /// Pass the month on the last day of the month, e.g. the 28th of February.
+    LastDayOfMonth,
+    /// Pass the month on the first day of the following month, e.g. the 1st of March.
+    #[default]
+    FirstDayOfNextMonth,
+}
+
+impl Personnummer {
+    /// Return the exact age of the person holding the personal identity number as years, months
+    /// and days at the current date.
+    #[cfg(feature = "clock")]
+    pub fn age(&self) -> Age {
+        self.age_exact_at(today())
+    }
+
+    /// Return the exact age of the person holding the personal identity number as years, months
+    /// and days at the given date. A month is passed when the same day of month is reached, if
+    /// that day doesn't exist in the month it's passed on the first day of the following month.
+    /// The age is 0 for dates before the birth date.
+    pub fn age_exact_at(&self, date: impl Into<Date>) -> Age {
+        self.age_exact_at_with_policy(date, LeapDayPolicy::default())
+    }
+
+    /// Same as [Personnummer::age_at()] but with the given [LeapDayPolicy].
+    pub fn age_at_with_policy(&self, date: impl Into<Date>, policy: LeapDayPolicy) -> u32 {
+        self.age_exact_at_with_policy(date, policy).years
+    }
+
+    /// Same as [Personnummer::age_exact_at()] but with the given [LeapDayPolicy].
+    pub fn age_exact_at_with_policy(&self, date: impl Into<Date>, policy: LeapDayPolicy) -> Age {
+        age_exact(self.birth_date(), date.into(), policy)
+    }
+
+    /// Check if the person holding the personal identity number is at least the given number of
+    /// years old at the current date.
+    #[cfg(feature = "clock")]
+    pub fn is_of_age(&self, years: u32) -> bool {
+        self.is_of_age_at(years, today())
+    }
+
+    /// Check if the person holding the personal identity number is at least the given number of
+    /// years old at the given date. A person turning the given age on the date is of age.
+    pub fn is_of_age_at(&self, years: u32, date: impl Into<Date>) -> bool {
+        self.age_at(date) >= years
+    }
+
+    /// Check if the person holding the personal identity number is younger than 18 at the current
+    /// date.
+    #[cfg(feature = "clock")]
+    pub fn is_minor(&self) -> bool {
+        self.is_minor_at(today())
+    }
+
+    /// Check if the person holding the personal identity number is younger than 18 at the given
+    /// date.
+    pub fn is_minor_at(&self, date: impl Into<Date>) -> bool {
+        !self.is_of_age_at(AGE_OF_MAJORITY, date)
+    }
+
+    /// Returns the date of the next birthday counted from the current date. If the birthday is
+    /// today, today is returned.
+    #[cfg(feature = "clock")]
+    pub fn next_birthday(&self) -> Date {
+        self.next_birthday_at(today())
+    }
+
+    /// Returns the date of the next birthday on or after the given date. People born on a leap day
+    /// have their birthday on the 1st of March in non leap years, see
+    /// [Personnummer::next_birthday_at_with_policy()] to change this. For dates before the birth
+    /// date the birth date is returned.
+    pub fn next_birthday_at(&self, date: impl Into<Date>) -> Date {
+        self.next_birthday_at_with_policy(date, LeapDayPolicy::default())
+    }
+
+    /// Same as [Personnummer::next_birthday_at()] but with the given [LeapDayPolicy].
+    pub fn next_birthday_at_with_policy(
+        &self,
+        date: impl Into<Date>,
+        policy: LeapDayPolicy,
+    ) -> Date {
+        let date = date.into();
+        let birth = self.birth_date();
+
+        if date <= birth {
+            return birth;
+        }
+
+        let years = (i64::from(date.year()) - i64::from(birth.year())) as u64;
+        let birthday = add_months(birth, years * 12, policy);
+
+        if birthday >= date {
+            birthday
+        } else {
+            add_months(birth, (years + 1) * 12, policy)
+        }
+    }
+
+    /// Returns the number of days until the next birthday counted from the current date, see
+    /// [Personnummer::next_birthday()].
+    #[cfg(feature = "clock")]
+    pub fn days_until_birthday(&self) -> u32 {
+        self.days_until_birthday_at(today())
+    }
+
+    /// Returns the number of days from the given date until the next birthday, see
+    /// [Personnummer::next_birthday_at()].
+    pub fn days_until_birthday_at(&self, date: impl Into<Date>) -> u32 {
+        let date = date.into();
+
+        date.days_until(self.next_birthday_at(date)) as u32
+    }
+}
+
+/// Returns the exact age at the date of a person born at the birth date, see
+/// [Personnummer::age_exact_at_with_policy()].
+pub(crate) fn age_exact(birth: Date, date: Date, policy: LeapDayPolicy) -> Age {
+    if date <= birth {
+        return Age {
+            years: 0,
+            months: 0,
+            days: 0,
+        };
+    }
+
+    // Calculated in 64 bits since the number of months overflows 32 bits for extreme dates.
+    let mut months = ((i64::from(date.year()) - i64::from(birth.year())) * 12
+        + i64::from(date.month())
+        - i64::from(birth.month())) as u64;
+
+    if add_months(birth, months, policy) > date {
+        months -= 1;
+    }
+
+    let days = add_months(birth, months, policy).days_until(date) as u32;
+
+    Age {
+        years: (months / 12) as u32,
+        months: (months % 12) as u32,
+        days,
+    }
+}
+
+/// Add months to a date. If the day doesn't exist in the resulting month the [LeapDayPolicy]
+/// decides which date to use. Years past the largest representable year saturate.
+fn add_months(date: Date, months: u64, policy: LeapDayPolicy) -> Date {
+    let total = u64::from(date.month() - 1) + months;
+    let year = i32::try_from(i64::from(date.year()) + (total / 12) as i64).unwrap_or(i32::MAX);
+    let month = (total % 12) as u32 + 1;
+
+    if let Some(d) = Date::from_ymd(year, month, date.day()) {
+        return d;
+    }
+
+    let date = match policy {
+        LeapDayPolicy::LastDayOfMonth => Date::from_ymd(year, month, days_in_month(year, month)),
+        LeapDayPolicy::FirstDayOfNextMonth if month == 12 => {
+            Date::from_ymd(year.saturating_add(1), 1, 1)
+        }
+        LeapDayPolicy::FirstDayOfNextMonth => Date::from_ymd(year, month + 1, 1),
+    };
+
+    date.expect("first and last day of month is valid")
+}
+
+#[cfg(all(test, feature = "clock"))]
+mod tests {
+    use super::*;
+
+    fn age(years: u32, months: u32, days: u32) -> Age {
+        Age {
+            years,
+            months,
+            days,
+        }
+    }
+
+    #[test]
+    fn test_leap_day_policy() {
+        let p = Personnummer::new("20000229-0017").unwrap();
+
+        let cases = vec![
+            ((2001, 2, 27), LeapDayPolicy::LastDayOfMonth, age(0, 11, 29)),
+            ((2001, 2, 28), LeapDayPolicy::LastDayOfMonth, age(1, 0, 0)),
+            ((2001, 3, 1), LeapDayPolicy::LastDayOfMonth, age(1, 0, 1)),
+            (
+                (2001, 2, 28),
+                LeapDayPolicy::FirstDayOfNextMonth,
+                age(0, 11, 30),
+            ),
+            (
+                (2001, 3, 1),
+                LeapDayPolicy::FirstDayOfNextMonth,
+                age(1, 0, 0),
+            ),
+            ((2004, 2, 29), LeapDayPolicy::LastDayOfMonth, age(4, 0, 0)),
+            (
+                (2004, 2, 29),
+                LeapDayPolicy::FirstDayOfNextMonth,
+                age(4, 0, 0),
+            ),
+        ];
+
+        for ((y, m, d), policy, expected) in cases {
+            let date = Date::from_ymd(y, m, d).unwrap();
+
+            assert_eq!(p.age_exact_at_with_policy(date, policy), expected);
+            assert_eq!(p.age_at_with_policy(date, policy), expected.years);
+        }
+    }
+
+    #[test]
+    fn test_is_of_age() {
+        let p = Personnummer::new("20000229-0017").unwrap();
+
+        let cases = vec![
+            ((2018, 2, 28), false),
+            ((2018, 3, 1), true),
+            ((2020, 2, 29), true),
+        ];
+
+        for ((y, m, d), of_age) in cases {
+            let date = Date::from_ymd(y, m, d).unwrap();
+
+            assert_eq!(p.is_of_age_at(18, date), of_age);
+            assert_eq!(p.is_minor_at(date), !of_age);
+        }
+
+        let date = Date::from_ymd(2020, 2, 29).unwrap();
+
+        assert!(p.is_of_age_at(20, date));
+        assert!(!p.is_of_age_at(21, date));
+    }
+
+    #[test]
+    fn test_next_birthday() {
+        let cases = vec![
+            ("19900101-0017", (2020, 1, 1), (2020, 1, 1), 0),
+            ("19900101-0017", (2020, 1, 2), (2021, 1, 1), 365),
+            ("19900615-0017", (2020, 1, 1), (2020, 6, 15), 166),
+            ("19900615-0017", (1980, 1, 1), (1990, 6, 15), 3818),
+            ("20000229-0017", (2001, 1, 1), (2001, 3, 1), 59),
+            ("20000229-0017", (2003, 3, 2), (2004, 2, 29), 364),
+        ];
+
+        for (pnr, (y, m, d), (by, bm, bd), days) in cases {
+            let p = Personnummer::new(pnr).unwrap();
+            let date = Date::from_ymd(y, m, d).unwrap();
+
+            assert_eq!(
+                p.next_birthday_at(date),
+                Date::from_ymd(by, bm, bd).unwrap()
+            );
+            assert_eq!(p.days_until_birthday_at(date), days);
+        }
+
+        let p = Personnummer::new("20000229-0017").unwrap();
+        let date = Date::from_ymd(2001, 1, 1).unwrap();
+
+        assert_eq!(
+            p.next_birthday_at_with_policy(date, LeapDayPolicy::LastDayOfMonth),
+            Date::from_ymd(2001, 2, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_age_exact_at() {
+        let cases = vec![
+            ("19900101-0017", (1990, 1, 1), age(0, 0, 0)),
+            ("19900101-0017", (1989, 1, 1), age(0, 0, 0)),
+            ("19900101-0017", (1990, 1, 31), age(0, 0, 30)),
+            ("19900101-0017", (2008, 1, 1), age(18, 0, 0)),
+            ("19900101-0017", (2007, 12, 31), age(17, 11, 30)),
+            ("19900131-0017", (1990, 2, 28), age(0, 0, 28)),
+            ("19900131-0017", (1990, 3, 1), age(0, 1, 0)),
+            ("19900131-0017", (1990, 3, 31), age(0, 2, 0)),
+            ("20000229-0017", (2001, 2, 28), age(0, 11, 30)),
+            ("20000229-0017", (2001, 3, 1), age(1, 0, 0)),
+            ("20000229-0017", (2004, 2, 29), age(4, 0, 0)),
+            ("800161-3294", (1980, 2, 15), age(0, 1, 14)),
+        ];
+
+        for (pnr, (y, m, d), expected) in cases {
+            let p = Personnummer::new(pnr).unwrap();
+            let date = Date::from_ymd(y, m, d).unwrap();
+
+            assert_eq!(p.age_exact_at(date), expected, "{} at {}", pnr, date);
+            assert_eq!(p.age_exact_at(date).years, p.age_at(date));
+        }
+    }
+
+    #[test]
+    fn test_age_extreme_dates() {
+        let p = Personnummer::new("19900101-0017").unwrap();
+        let last = Date::from_ymd(i32::MAX, 12, 31).unwrap();
+
+        assert_eq!(p.age_exact_at(last), age((i32::MAX - 1990) as u32, 11, 30));
+        assert_eq!(
+            p.age_exact_at(Date::from_ymd(i32::MIN, 1, 1).unwrap()),
+            age(0, 0, 0)
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! Helpers for the personal number format used by BankID, which is the twelve digit format without
+//! separator, e.g. `199001010017`. It's the format of `personalNumber` in the completion data
+//! returned by the BankID API and the format it expects in requests.
+//!
+//! With the `serde` feature the module can be used with `#[serde(with = "personnummer::bankid")]`
+//! to serialize a [Personnummer] in this format and only deserialize valid numbers in it.
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! use personnummer::Personnummer;
+//!
+//! #[derive(serde::Deserialize)]
+//! #[serde(rename_all = "camelCase")]
+//! struct User {
+//!     #[serde(with = "personnummer::bankid")]
+//!     personal_number: Personnummer,
+//!     name: String,
+//! }
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
+
+use crate::{parse_valid, FormatOptions, Personnummer, PersonnummerError};
+
+use alloc::string::String;
+
+/// The number of digits in the BankID format.
+const LENGTH: usize = 12;
+
+/// Parse a personal identity number in the BankID format. Only twelve digits without separator
+/// are accepted and the number must be valid, the same as [parse_valid()].
+pub fn parse(personal_number: &str) -> Result<Personnummer, PersonnummerError> {
+    for (offset, found) in personal_number.char_indices() {
+        match found {
+            '0'..='9' => {}
+            '-' | '+' => return Err(PersonnummerError::InvalidSeparator { offset, found }),
+            _ => return Err(PersonnummerError::NonDigit { offset, found }),
+        }
+    }
+
+    if personal_number.len() != LENGTH {
+        return Err(PersonnummerError::WrongLength(personal_number.len()));
+    }
+
+    parse_valid(personal_number)
+}
+
+impl Personnummer {
+    /// Returns the personal identity number in the BankID format, e.g. `199001010017`.
+    pub fn to_bankid_string(&self) -> String {
+        self.format_with(FormatOptions::twelve_digits())
+    }
+}
+
+/// Serialize a [Personnummer] in the BankID format, for use with `#[serde(with = "...")]`.
+#[cfg(feature = "serde")]
+pub fn serialize<S: serde::Serializer>(
+    pnr: &Personnummer,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&pnr.to_bankid_string())
+}
+
+/// Deserialize a [Personnummer] in the BankID format with [parse()], for use with
+/// `#[serde(with = "...")]`.
+#[cfg(feature = "serde")]
+pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Personnummer, D::Error> {
+    use serde::de::{Deserialize, Error};
+
+    let personal_number = String::deserialize(deserializer)?;
+
+    parse(&personal_number).map_err(Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("199001010017").unwrap().to_bankid_string(),
+            "199001010017"
+        );
+        assert_eq!(
+            parse("198001613291").unwrap().to_bankid_string(),
+            "198001613291"
+        );
+
+        let cases = vec![
+            ("9001010017", PersonnummerError::WrongLength(10)),
+            ("19900101001", PersonnummerError::WrongLength(11)),
+            (
+                "19900101-0017",
+                PersonnummerError::InvalidSeparator {
+                    offset: 8,
+                    found: '-',
+                },
+            ),
+            (
+                " 199001010017",
+                PersonnummerError::NonDigit {
+                    offset: 0,
+                    found: ' ',
+                },
+            ),
+            (
+                "199001010018",
+                PersonnummerError::InvalidChecksum {
+                    expected: 7,
+                    found: 8,
+                },
+            ),
+        ];
+
+        for (input, error) in cases {
+            assert_eq!(parse(input).unwrap_err(), error, "{}", input);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct User {
+            #[serde(with = "crate::bankid")]
+            personal_number: Personnummer,
+        }
+
+        let json = r#"{"personalNumber":"198001613291"}"#;
+        let user: User = serde_json::from_str(json).unwrap();
+
+        assert_eq!(user.personal_number.to_string(), "19800161-3291");
+        assert_eq!(serde_json::to_string(&user).unwrap(), json);
+
+        let error = serde_json::from_str::<User>(r#"{"personalNumber":"800161-3291"}"#)
+            .unwrap_err()
+            .to_string();
+
+        assert!(
+            error.starts_with("Invalid separator '-' at position 6"),
+            "{}",
+            error
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{parse_valid, Personnummer, PersonnummerError};
+
+use std::io::{self, BufRead};
+
+/// [BatchSummary] summarizes the result of validating a batch of personal identity numbers with
+/// [validate_all_summary()].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BatchSummary {
+    /// The number of validated inputs.
+    pub total: usize,
+    /// The number of valid personal identity numbers.
+    pub valid: usize,
+    /// The index and error for each invalid input.
+    pub errors: Vec<(usize, PersonnummerError)>,
+}
+
+impl BatchSummary {
+    /// The number of invalid inputs.
+    pub fn invalid(&self) -> usize {
+        self.errors.len()
+    }
+}
+
+/// Validate each input with [parse_valid()] and return an iterator over the results. Errors are
+/// tagged with the index of the input.
+pub fn validate_all<I>(
+    pnrs: I,
+) -> impl Iterator<Item = Result<Personnummer, (usize, PersonnummerError)>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    pnrs.into_iter()
+        .enumerate()
+        .map(|(idx, pnr)| parse_valid(pnr.as_ref()).map_err(|e| (idx, e)))
+}
+
+/// Validate each input with [parse_valid()] and return a [BatchSummary] of the result.
+pub fn validate_all_summary<I>(pnrs: I) -> BatchSummary
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    validate_all(pnrs).fold(BatchSummary::default(), |mut summary, result| {
+        summary.total += 1;
+
+        match result {
+            Ok(_) => summary.valid += 1,
+            Err(e) => summary.errors.push(e),
+        }
+
+        summary
+    })
+}
+
+/// Same as [validate_all()] but validates a slice in parallel on the rayon thread pool. The
+/// results are in the same order as the input.
+#[cfg(feature = "rayon")]
+pub fn par_validate_all<S>(pnrs: &[S]) -> Vec<Result<Personnummer, (usize, PersonnummerError)>>
+where
+    S: AsRef<str> + Sync,
+{
+    use rayon::prelude::*;
+
+    pnrs.par_iter()
+        .enumerate()
+        .map(|(idx, pnr)| parse_valid(pnr.as_ref()).map_err(|e| (idx, e)))
+        .collect()
+}
+
+/// Same as [validate_all_summary()] but validates a slice in parallel on the rayon thread pool.
+/// The errors are in the same order as the input.
+#[cfg(feature = "rayon")]
+pub fn par_validate_all_summary<S>(pnrs: &[S]) -> BatchSummary
+where
+    S: AsRef<str> + Sync,
+{
+    use rayon::prelude::*;
+
+    let errors = pnrs
+        .par_iter()
+        .enumerate()
+        .filter_map(|(idx, pnr)| parse_valid(pnr.as_ref()).err().map(|e| (idx, e)))
+        .collect::<Vec<_>>();
+
+    BatchSummary {
+        total: pnrs.len(),
+        valid: pnrs.len() - errors.len(),
+        errors,
+    }
+}
+
+/// Same as [validate_all()] but validates each item of a stream, e.g. lines from an async reader
+/// or messages from a queue consumer.
+#[cfg(feature = "async")]
+pub fn validate_stream<S>(
+    pnrs: S,
+) -> impl futures::Stream<Item = Result<Personnummer, (usize, PersonnummerError)>>
+where
+    S: futures::Stream,
+    S::Item: AsRef<str>,
+{
+    use futures::StreamExt;
+
+    pnrs.enumerate()
+        .map(|(idx, pnr)| parse_valid(pnr.as_ref()).map_err(|e| (idx, e)))
+}
+
+/// [Line] is the result of validating a single line with [read_lines()].
+#[derive(Debug)]
+pub struct Line {
+    /// The line number, starting at 1.
+    pub number: usize,
+    /// The result of validating the line with [parse_valid()].
+    pub result: Result<Personnummer, PersonnummerError>,
+}
+
+/// Returns an iterator validating each line of the reader with [parse_valid()]. Lines are read one
+/// at a time so the whole input is never kept in memory. Surrounding whitespace is trimmed and
+/// blank lines are skipped. A line that isn't valid UTF-8 gives an error with kind
+/// [io::ErrorKind::InvalidData] and reading can continue with the next line.
+pub fn read_lines<R: BufRead>(reader: R) -> Lines<R> {
+    Lines {
+        reader,
+        buf: Vec::new(),
+        number: 0,
+    }
+}
+
+/// [Lines] is an iterator over validated lines of a reader, created by [read_lines()].
+pub struct Lines<R> {
+    reader: R,
+    buf: Vec<u8>,
+    number: usize,
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = io::Result<Line>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buf.clear();
+
+            match self.reader.read_until(b'\n', &mut self.buf) {
+                Ok(0) => return None,
+                Ok(_) => self.number += 1,
+                Err(e) => return Some(Err(e)),
+            }
+
+            // The line is counted before it's decoded so numbers stay correct after invalid lines.
+            let line = match std::str::from_utf8(&self.buf) {
+                Ok(line) => line.trim(),
+                Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+            };
+
+            if !line.is_empty() {
+                return Some(Ok(Line {
+                    number: self.number,
+                    result: parse_valid(line),
+                }));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_all() {
+        let results =
+            validate_all(vec!["19900101-0017", "19900101-0018", "invalid"]).collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap().format().long(),
+            "19900101-0017"
+        );
+        assert_eq!(
+            results[1].as_ref().unwrap_err(),
+            &(
+                1,
+                PersonnummerError::InvalidChecksum {
+                    expected: 7,
+                    found: 8
+                }
+            )
+        );
+        assert_eq!(results[2].as_ref().unwrap_err().0, 2);
+    }
+
+    #[test]
+    fn test_read_lines() {
+        let input = "19900101-0017\r\n\n  800161-3291  \n19900101-0018";
+
+        let lines = read_lines(input.as_bytes())
+            .map(|l| l.unwrap())
+            .map(|l| (l.number, l.result.is_ok()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines, vec![(1, true), (3, true), (4, false)]);
+
+        let input = b"19900101-0017\n\xff\xfe\n800161-3291\n";
+        let lines = read_lines(&input[..]).collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].as_ref().unwrap().number, 1);
+        assert_eq!(
+            lines[1].as_ref().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(lines[2].as_ref().unwrap().number, 3);
+        assert!(lines[2].as_ref().unwrap().result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_all_summary() {
+        let input = vec![
+            "19900101-0017".to_string(),
+            "800161-3291".to_string(),
+            "19900101-0000".to_string(),
+        ];
+        let summary = validate_all_summary(&input);
+
+        assert_eq!(summary.total, 3);
+        assert_eq!(summary.valid, 2);
+        assert_eq!(summary.invalid(), 1);
+        assert_eq!(summary.errors, vec![(2, PersonnummerError::InvalidSerial)]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_validate_all() {
+        let input = (0..1_000)
+            .map(|i| match i % 3 {
+                0 => "19900101-0017",
+                1 => "19900101-0018",
+                _ => "invalid",
+            })
+            .collect::<Vec<_>>();
+
+        let sequential = validate_all(&input)
+            .map(|r| r.map(|p| p.to_u64()))
+            .collect::<Vec<_>>();
+        let parallel = par_validate_all(&input)
+            .into_iter()
+            .map(|r| r.map(|p| p.to_u64()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(
+            par_validate_all_summary(&input),
+            validate_all_summary(&input)
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_validate_stream() {
+        use futures::{executor::block_on, stream, StreamExt};
+
+        let input = stream::iter(vec![
+            "19900101-0017".to_string(),
+            "19900101-0018".to_string(),
+        ]);
+
+        let results = block_on(validate_stream(input).collect::<Vec<_>>());
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert_eq!(results[1].as_ref().unwrap_err().0, 1);
+    }
+}
@@ -0,0 +1,178 @@
+//! Generate random valid personal identity numbers for test environments.
+
+use personnummer::{Date, Gender, Personnummer};
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// [Options] describes which personal identity numbers to generate.
+#[derive(Debug)]
+pub struct Options {
+    pub from: Date,
+    pub to: Date,
+    pub gender: Option<Gender>,
+    pub coordination: bool,
+    pub seed: Option<u64>,
+}
+
+/// [Generator] creates random valid personal identity numbers born between two dates. It's
+/// deterministic for a given seed.
+pub struct Generator {
+    options: Options,
+    days: u64,
+    rng: SplitMix64,
+}
+
+impl Generator {
+    /// Returns a new [Generator], or an error if the date range is empty, starts before year 0 or
+    /// ends after today.
+    pub fn new(options: Options) -> Result<Self, String> {
+        if options.to > Date::today() {
+            return Err(format!("--to {} is after today", options.to));
+        }
+
+        if options.from.year() < 0 {
+            return Err(format!("--from {} is before year 0", options.from));
+        }
+
+        if options.from > options.to {
+            return Err(format!(
+                "--from {} is after --to {}",
+                options.from, options.to
+            ));
+        }
+
+        let days = options.from.days_until(options.to) as u64 + 1;
+        let seed = options.seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos() as u64)
+        });
+
+        Ok(Generator {
+            options,
+            days,
+            rng: SplitMix64(seed),
+        })
+    }
+}
+
+impl Iterator for Generator {
+    type Item = Personnummer;
+
+    fn next(&mut self) -> Option<Personnummer> {
+        let offset = self.rng.below(self.days);
+        let date = chrono::NaiveDate::from(self.options.from) + chrono::Days::new(offset);
+        let date = Date::from(date);
+
+        // The last digit of the serial is odd for men and even for women.
+        let male = match self.options.gender {
+            Some(gender) => gender == Gender::Male,
+            None => self.rng.below(2) == 1,
+        };
+        let serial = match self.rng.below(500) as u32 * 2 + u32::from(male) {
+            0 => 2,
+            serial => serial,
+        };
+
+        let day = if self.options.coordination {
+            date.day() + 60
+        } else {
+            date.day()
+        };
+
+        let value = date.year() as u64 * 100_000_000
+            + date.month() as u64 * 1_000_000
+            + day as u64 * 10_000
+            + serial as u64 * 10;
+        let pnr = Personnummer::from_u64(value).expect("generated date is valid");
+
+        Some(
+            Personnummer::from_u64(value + pnr.checksum() as u64)
+                .expect("generated number is valid"),
+        )
+    }
+}
+
+/// [SplitMix64] is a small and fast pseudo random number generator, see
+/// https://prng.di.unimi.it/splitmix64.c. It's not suitable for anything but test data.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..n`.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> Options {
+        Options {
+            from: Date::from_ymd(1990, 1, 1).unwrap(),
+            to: Date::from_ymd(1990, 12, 31).unwrap(),
+            gender: None,
+            coordination: false,
+            seed: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let generated = Generator::new(options()).unwrap().take(1_000);
+
+        for pnr in generated {
+            assert!(pnr.valid());
+            assert_eq!(pnr.year(), 1990);
+            assert!(!pnr.is_coordination_number());
+        }
+
+        let options = Options {
+            gender: Some(Gender::Female),
+            coordination: true,
+            ..options()
+        };
+
+        for pnr in Generator::new(options).unwrap().take(1_000) {
+            assert!(pnr.valid());
+            assert!(pnr.is_female());
+            assert!(pnr.is_coordination_number());
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        let first = Generator::new(options()).unwrap().take(10);
+        let second = Generator::new(options()).unwrap().take(10);
+
+        assert!(first.zip(second).all(|(a, b)| a.to_u64() == b.to_u64()));
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        let empty = Options {
+            from: Date::from_ymd(1991, 1, 1).unwrap(),
+            ..options()
+        };
+
+        assert!(Generator::new(empty).is_err());
+
+        let before_year_zero = Options {
+            from: Date::from_ymd(-1, 1, 1).unwrap(),
+            ..options()
+        };
+
+        assert!(Generator::new(before_year_zero).is_err());
+    }
+}
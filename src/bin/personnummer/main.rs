@@ -0,0 +1,463 @@
+//! Command line interface for validating and formatting personal identity numbers. Exits with 0 if
+//! all input is valid, 1 if any input is invalid and 2 for usage errors.
+
+mod generate;
+mod redact;
+mod stats;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use personnummer::{parse_valid, scan::RedactStyle, Date, Gender, Personnummer};
+use serde::Serialize;
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "personnummer",
+    version,
+    about = "Validate Swedish personal identity numbers"
+)]
+struct Cli {
+    /// Print JSON instead of text.
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Check if personal identity numbers are valid.
+    Validate {
+        /// Read one number per line from stdin and print one JSON record per line.
+        #[arg(long, conflicts_with = "pnrs")]
+        stdin: bool,
+
+        #[arg(required_unless_present = "stdin")]
+        pnrs: Vec<String>,
+    },
+    /// Print personal identity numbers in the long or short format.
+    Format {
+        /// Print the short format, e.g. 900101-0017, instead of the long format.
+        #[arg(long)]
+        short: bool,
+
+        #[arg(required = true)]
+        pnrs: Vec<String>,
+    },
+    /// Print the birth date, age, gender and if it's a coordination number.
+    Info {
+        #[arg(required = true)]
+        pnrs: Vec<String>,
+    },
+    /// Generate random valid personal identity numbers for testing.
+    Generate {
+        /// The number of personal identity numbers to generate.
+        #[arg(short = 'n', long, default_value_t = 10)]
+        count: usize,
+
+        /// The earliest birth date, e.g. 1990-01-01.
+        #[arg(long, value_parser = parse_date, default_value = "1900-01-01")]
+        from: Date,
+
+        /// The latest birth date, defaults to today.
+        #[arg(long, value_parser = parse_date)]
+        to: Option<Date>,
+
+        /// Only generate numbers for one gender, female or male.
+        #[arg(long, value_parser = parse_gender)]
+        gender: Option<Gender>,
+
+        /// Generate coordination numbers.
+        #[arg(long)]
+        coordination: bool,
+
+        /// Seed to generate the same numbers every time.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Print the number of valid numbers, the gender split, the share of coordination numbers and
+    /// the age distribution of files with one number per line.
+    Stats {
+        /// Files to read, reads from stdin if none or `-` is given.
+        files: Vec<PathBuf>,
+    },
+    /// Replace valid personal identity numbers in text files or logs and print the result. A
+    /// summary of the number of redactions is printed to stderr.
+    Redact {
+        /// What to replace each personal identity number with.
+        #[arg(long, value_enum, default_value_t = Style::Full)]
+        style: Style,
+
+        /// The key used for pseudonyms, required with `--style pseudonym`.
+        #[arg(long, env = "PERSONNUMMER_KEY", hide_env_values = true)]
+        key: Option<String>,
+
+        /// Files to read, reads from stdin if none or `-` is given.
+        files: Vec<PathBuf>,
+    },
+}
+
+/// [Style] selects the [scan::RedactStyle] of the `redact` command.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Style {
+    /// Replace every digit with `X`, e.g. `XXXXXX-XXXX`.
+    Full,
+    /// Keep the year as written, e.g. `90XXXX-XXXX`.
+    KeepBirthYear,
+    /// Replace the number with a keyed HMAC-SHA256 pseudonym.
+    Pseudonym,
+}
+
+/// [Validation] is the result of validating one input, where the normalized value is the long
+/// format of a valid number.
+#[derive(Debug, Serialize)]
+struct Validation<'a> {
+    input: &'a str,
+    valid: bool,
+    normalized: Option<String>,
+    error: Option<String>,
+}
+
+impl<'a> Validation<'a> {
+    fn new(input: &'a str) -> Self {
+        match parse_valid(input) {
+            Ok(pnr) => Validation {
+                input,
+                valid: true,
+                normalized: Some(pnr.format().long()),
+                error: None,
+            },
+            Err(e) => Validation {
+                input,
+                valid: false,
+                normalized: None,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+/// [Info] describes a valid personal identity number.
+#[derive(Debug, Serialize)]
+struct Info {
+    long: String,
+    short: String,
+    birth_date: String,
+    age: i32,
+    gender: Gender,
+    coordination: bool,
+}
+
+impl From<&Personnummer> for Info {
+    fn from(pnr: &Personnummer) -> Self {
+        let formatted = pnr.format();
+
+        Info {
+            long: formatted.long(),
+            short: formatted.short(),
+            birth_date: pnr.birth_date().to_string(),
+            age: pnr.get_age(),
+            gender: pnr.gender(),
+            coordination: pnr.is_coordination_number(),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let valid = match &cli.command {
+        Command::Validate { stdin: true, .. } => match validate_lines(io::stdin().lock()) {
+            Ok(valid) => valid,
+            Err(e) => {
+                eprintln!("personnummer: {}", e);
+                return ExitCode::from(2);
+            }
+        },
+        Command::Validate { pnrs, .. } => validate(pnrs, cli.json),
+        Command::Format { short, pnrs } => each_valid(pnrs, |pnr| {
+            let formatted = pnr.format();
+            let value = if *short {
+                formatted.short()
+            } else {
+                formatted.long()
+            };
+
+            if cli.json {
+                println!("{}", json(&value));
+            } else {
+                println!("{}", value);
+            }
+        }),
+        Command::Info { pnrs } => each_valid(pnrs, |pnr| {
+            let info = Info::from(pnr);
+
+            if cli.json {
+                println!("{}", json(&info));
+            } else {
+                println!("long:         {}", info.long);
+                println!("short:        {}", info.short);
+                println!("birth date:   {}", info.birth_date);
+                println!("age:          {}", info.age);
+                println!("gender:       {}", info.gender);
+                println!("coordination: {}", info.coordination);
+            }
+        }),
+        Command::Generate {
+            count,
+            from,
+            to,
+            gender,
+            coordination,
+            seed,
+        } => {
+            let options = generate::Options {
+                from: *from,
+                to: to.unwrap_or_else(Date::today),
+                gender: *gender,
+                coordination: *coordination,
+                seed: *seed,
+            };
+
+            let generator = match generate::Generator::new(options) {
+                Ok(generator) => generator,
+                Err(e) => {
+                    eprintln!("personnummer: {}", e);
+                    return ExitCode::from(2);
+                }
+            };
+
+            for pnr in generator.take(*count) {
+                if cli.json {
+                    println!("{}", json(&Info::from(&pnr)));
+                } else {
+                    println!("{}", pnr.format().long());
+                }
+            }
+
+            true
+        }
+        Command::Stats { files } => {
+            let stats = match stats(files) {
+                Ok(stats) => stats,
+                Err(e) => {
+                    eprintln!("personnummer: {}", e);
+                    return ExitCode::from(2);
+                }
+            };
+
+            if cli.json {
+                println!("{}", json(&stats));
+            } else {
+                print!("{}", stats);
+            }
+
+            true
+        }
+        Command::Redact { style, key, files } => {
+            let style = match (style, key) {
+                (Style::Full, _) => RedactStyle::Full,
+                (Style::KeepBirthYear, _) => RedactStyle::KeepBirthYear,
+                (Style::Pseudonym, Some(key)) => RedactStyle::Pseudonym(key.as_bytes().to_vec()),
+                (Style::Pseudonym, None) => {
+                    eprintln!("personnummer: --style pseudonym requires --key");
+                    return ExitCode::from(2);
+                }
+            };
+
+            let mut summary = redact::Summary::default();
+            let mut stdout = io::stdout().lock();
+            let result = readers(files)
+                .into_iter()
+                .try_for_each(|reader| redact::redact(reader?, &mut stdout, &style, &mut summary));
+
+            if let Err(e) = result.and_then(|_| stdout.flush()) {
+                eprintln!("personnummer: {}", e);
+                return ExitCode::from(2);
+            }
+
+            if cli.json {
+                eprintln!("{}", json(&summary));
+            } else {
+                eprintln!("personnummer: {}", summary);
+            }
+
+            true
+        }
+    };
+
+    if valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Print if each input is valid. Returns false if any input is invalid.
+fn validate(pnrs: &[String], as_json: bool) -> bool {
+    let mut all_valid = true;
+
+    for input in pnrs {
+        let validation = Validation::new(input);
+        all_valid &= validation.valid;
+
+        if as_json {
+            println!("{}", json(&validation));
+        } else {
+            match validation.error {
+                None => println!("{}: valid", input),
+                Some(e) => println!("{}: {}", input, e),
+            }
+        }
+    }
+
+    all_valid
+}
+
+/// Validate each line of the reader and print a [Validation] as JSON for each line. Surrounding
+/// whitespace is trimmed and blank lines are skipped. Lines that aren't valid UTF-8 are decoded
+/// lossily and reported as invalid. Returns false if any line is invalid.
+fn validate_lines<R: BufRead>(mut reader: R) -> io::Result<bool> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    let mut line = Vec::new();
+    let mut all_valid = true;
+
+    loop {
+        line.clear();
+
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+
+        let decoded = String::from_utf8_lossy(&line);
+        let input = decoded.trim();
+
+        if input.is_empty() {
+            continue;
+        }
+
+        let validation = Validation::new(input);
+        all_valid &= validation.valid;
+
+        serde_json::to_writer(&mut out, &validation)?;
+        out.write_all(b"\n")?;
+    }
+
+    out.flush()?;
+
+    Ok(all_valid)
+}
+
+/// Parse each input and call the function for the valid ones, errors are printed to stderr.
+/// Returns false if any input is invalid.
+fn each_valid<F>(pnrs: &[String], mut f: F) -> bool
+where
+    F: FnMut(&Personnummer),
+{
+    let mut all_valid = true;
+
+    for input in pnrs {
+        match parse_valid(input) {
+            Ok(pnr) => f(&pnr),
+            Err(e) => {
+                all_valid = false;
+                eprintln!("personnummer: {}: {}", input, e);
+            }
+        }
+    }
+
+    all_valid
+}
+
+/// Collect [stats::Stats] for each line of the files, or stdin if no files are given. Lines that
+/// aren't valid UTF-8 are counted as invalid.
+fn stats(files: &[PathBuf]) -> io::Result<stats::Stats> {
+    let mut stats = stats::Stats::new();
+
+    for reader in readers(files) {
+        for line in personnummer::read_lines(reader?) {
+            match line {
+                Ok(line) => match line.result {
+                    Ok(pnr) => stats.add_valid(&pnr),
+                    Err(_) => stats.add_invalid(),
+                },
+                Err(e) if e.kind() == io::ErrorKind::InvalidData => stats.add_invalid(),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    Ok(stats)
+}
+
+/// Returns a reader for each file where `-` is stdin, or only stdin if there are no files. Stdin
+/// can only be read once so repeating `-` is an error.
+fn readers(files: &[PathBuf]) -> Vec<io::Result<Box<dyn BufRead>>> {
+    if files.is_empty() {
+        return vec![Ok(Box::new(io::stdin().lock()))];
+    }
+
+    let mut stdin = false;
+
+    files
+        .iter()
+        .map(|path| -> io::Result<Box<dyn BufRead>> {
+            if path.as_os_str() == "-" {
+                if stdin {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "-: stdin can only be read once",
+                    ));
+                }
+
+                stdin = true;
+
+                return Ok(Box::new(io::stdin().lock()));
+            }
+
+            File::open(path)
+                .map(|f| Box::new(BufReader::new(f)) as Box<dyn BufRead>)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+        })
+        .collect()
+}
+
+/// Parse a date in ISO 8601 format with a year between 0 and 9999, e.g. `1990-01-01`.
+fn parse_date(value: &str) -> Result<Date, String> {
+    let mut parts = value.splitn(3, '-');
+    let year = parts
+        .next()
+        .and_then(|year| year.parse::<i32>().ok())
+        .filter(|year| (0..=9999).contains(year));
+    let month = parts.next().and_then(|month| month.parse::<u32>().ok());
+    let day = parts.next().and_then(|day| day.parse::<u32>().ok());
+
+    match (year, month, day) {
+        (Some(year), Some(month), Some(day)) => Date::from_ymd(year, month, day),
+        _ => None,
+    }
+    .ok_or_else(|| format!("invalid date '{}', expected YYYY-MM-DD", value))
+}
+
+fn parse_gender(value: &str) -> Result<Gender, String> {
+    match value {
+        "female" => Ok(Gender::Female),
+        "male" => Ok(Gender::Male),
+        _ => Err(format!(
+            "invalid gender '{}', expected female or male",
+            value
+        )),
+    }
+}
+
+/// Serialize the value as a single JSON line.
+fn json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("value is serializable")
+}
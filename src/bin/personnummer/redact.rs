@@ -0,0 +1,107 @@
+//! Redact valid personal identity numbers in text, one line at a time.
+
+use personnummer::scan::{self, RedactStyle};
+use serde::Serialize;
+
+use std::{
+    fmt,
+    io::{self, BufRead, Write},
+};
+
+/// [Summary] counts the lines read and personal identity numbers redacted.
+#[derive(Debug, Default, Serialize, PartialEq, Eq)]
+pub struct Summary {
+    pub lines: usize,
+    pub redacted_lines: usize,
+    pub redacted: usize,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "redacted {} personal identity numbers on {} of {} lines",
+            self.redacted, self.redacted_lines, self.lines
+        )
+    }
+}
+
+/// Copy the reader to the writer with all valid personal identity numbers replaced according to
+/// the [RedactStyle]. Lines that aren't valid UTF-8 are redacted with invalid bytes replaced by
+/// `U+FFFD` so no number is ever passed through unredacted.
+pub fn redact<R: BufRead, W: Write>(
+    mut reader: R,
+    writer: &mut W,
+    style: &RedactStyle,
+    summary: &mut Summary,
+) -> io::Result<()> {
+    let mut buf = Vec::new();
+
+    loop {
+        buf.clear();
+
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            return Ok(());
+        }
+
+        let redacted = scan::redact(&String::from_utf8_lossy(&buf), style);
+
+        summary.lines += 1;
+
+        if !redacted.replacements.is_empty() {
+            summary.redacted_lines += 1;
+            summary.redacted += redacted.replacements.len();
+        }
+
+        writer.write_all(redacted.text.as_bytes())?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact() {
+        let input = "user 199001010017 logged in\nno number here\n800161-3291, 900101-0017";
+        let mut output = Vec::new();
+        let mut summary = Summary::default();
+
+        redact(
+            input.as_bytes(),
+            &mut output,
+            &RedactStyle::Full,
+            &mut summary,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "user XXXXXXXXXXXX logged in\nno number here\nXXXXXX-XXXX, XXXXXX-XXXX"
+        );
+        assert_eq!(
+            summary,
+            Summary {
+                lines: 3,
+                redacted_lines: 2,
+                redacted: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_redact_invalid_utf8() {
+        let input = b"\xff 900101-0017\n";
+        let mut output = Vec::new();
+
+        redact(
+            &input[..],
+            &mut output,
+            &RedactStyle::KeepBirthYear,
+            &mut Summary::default(),
+        )
+        .unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "\u{fffd} 90XXXX-XXXX\n");
+    }
+}
@@ -0,0 +1,172 @@
+//! Summarize a list of personal identity numbers.
+
+use personnummer::{stats::Summary, Personnummer};
+use serde::{Serialize, Serializer};
+
+use std::fmt;
+
+/// The lower bound of each age bucket, the last bucket has no upper bound.
+const AGE_BUCKETS: [u32; 6] = [0, 18, 30, 45, 65, 80];
+
+/// [Stats] counts valid and invalid input, with a [Summary] of the valid personal identity
+/// numbers.
+#[derive(Debug)]
+pub struct Stats {
+    pub total: usize,
+    pub invalid: usize,
+    pub summary: Summary,
+}
+
+/// [AgeBucket] is the number of persons in an age range.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct AgeBucket {
+    pub range: String,
+    pub count: usize,
+}
+
+impl Stats {
+    pub fn new() -> Self {
+        Stats {
+            total: 0,
+            invalid: 0,
+            summary: Summary::new(),
+        }
+    }
+
+    pub fn add_valid(&mut self, pnr: &Personnummer) {
+        self.total += 1;
+        self.summary.add(pnr);
+    }
+
+    pub fn add_invalid(&mut self) {
+        self.total += 1;
+        self.invalid += 1;
+    }
+
+    pub fn ages(&self) -> Vec<AgeBucket> {
+        self.summary
+            .age_buckets(&AGE_BUCKETS)
+            .expect("age buckets are in ascending order")
+            .into_iter()
+            .map(|bucket| AgeBucket {
+                range: match bucket.max {
+                    Some(max) => format!("{}-{}", bucket.min, max),
+                    None => format!("{}+", bucket.min),
+                },
+                count: bucket.count,
+            })
+            .collect()
+    }
+}
+
+impl Serialize for Stats {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Json {
+            total: usize,
+            valid: usize,
+            invalid: usize,
+            female: usize,
+            male: usize,
+            coordination: usize,
+            mean_age: Option<f64>,
+            median_age: Option<f64>,
+            ages: Vec<AgeBucket>,
+        }
+
+        Json {
+            total: self.total,
+            valid: self.summary.count,
+            invalid: self.invalid,
+            female: self.summary.female,
+            male: self.summary.male,
+            coordination: self.summary.coordination,
+            mean_age: self.summary.mean_age(),
+            median_age: self.summary.median_age(),
+            ages: self.ages(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Displays the counts as a table with the share of valid numbers in percent.
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let valid = self.summary.count;
+        let share = |count: usize| {
+            if valid == 0 {
+                0.0
+            } else {
+                count as f64 * 100.0 / valid as f64
+            }
+        };
+
+        writeln!(f, "{:<14}{:>10}", "total", self.total)?;
+        writeln!(f, "{:<14}{:>10}", "valid", valid)?;
+        writeln!(f, "{:<14}{:>10}", "invalid", self.invalid)?;
+        writeln!(f)?;
+
+        for (name, count) in [
+            ("female", self.summary.female),
+            ("male", self.summary.male),
+            ("coordination", self.summary.coordination),
+        ] {
+            writeln!(f, "{:<14}{:>10}{:>8.1}%", name, count, share(count))?;
+        }
+
+        writeln!(f)?;
+
+        for (name, age) in [
+            ("mean age", self.summary.mean_age()),
+            ("median age", self.summary.median_age()),
+        ] {
+            writeln!(f, "{:<14}{:>10.1}", name, age.unwrap_or(0.0))?;
+        }
+
+        writeln!(f)?;
+
+        for bucket in self.ages() {
+            writeln!(
+                f,
+                "{:<14}{:>10}{:>8.1}%",
+                format!("age {}", bucket.range),
+                bucket.count,
+                share(bucket.count)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let mut stats = Stats::new();
+
+        stats.add_valid(&Personnummer::new("19900101-0017").unwrap());
+        stats.add_valid(&Personnummer::new("19800161-3291").unwrap());
+        stats.add_valid(&Personnummer::new("19130401+2931").unwrap());
+        stats.add_invalid();
+
+        let json = serde_json::to_value(&stats).unwrap();
+
+        assert_eq!(json["total"], 4);
+        assert_eq!(json["valid"], 3);
+        assert_eq!(json["invalid"], 1);
+        assert_eq!(json["male"], 3);
+        assert_eq!(json["coordination"], 1);
+        assert_eq!(
+            stats
+                .ages()
+                .iter()
+                .map(|b| b.range.as_str())
+                .collect::<Vec<_>>(),
+            vec!["0-17", "18-29", "30-44", "45-64", "65-79", "80+"]
+        );
+        assert_eq!(stats.ages()[5].count, 1);
+    }
+}
@@ -0,0 +1,147 @@
+//! Validate large batches of personal identity numbers stored in the fixed width twelve digit
+//! format without separator, e.g. `199001010017`.
+//!
+//! The validation is a tight table driven loop working directly on the bytes without parsing a
+//! [crate::Personnummer], which is several times faster than calling [crate::Personnummer::new()]
+//! and [crate::Personnummer::valid()] for each number. The result is the same as
+//! [crate::Personnummer::valid()] for each number, run `cargo bench` to compare the throughput.
+
+use alloc::vec::Vec;
+
+/// The Luhn value of a doubled digit, indexed by the digit.
+const DOUBLED: [u8; 10] = [0, 2, 4, 6, 8, 1, 3, 5, 7, 9];
+
+/// Days in each month of a non leap year, indexed by the month.
+const DAYS_IN_MONTH: [u8; 13] = [0, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Validate all numbers and return if each number is valid, in the same order.
+pub fn validate(numbers: &[[u8; 12]]) -> Vec<bool> {
+    numbers.iter().map(is_valid).collect()
+}
+
+/// Returns the number of valid numbers.
+pub fn count_valid(numbers: &[[u8; 12]]) -> usize {
+    numbers.iter().filter(|n| is_valid(n)).count()
+}
+
+/// Check if a single twelve digit number is valid. This is a `const fn` so it can be used for
+/// compile time checks, e.g. `const _: () = assert!(is_valid(b"199001010017"));`.
+pub const fn is_valid(number: &[u8; 12]) -> bool {
+    let mut d = [0u8; 12];
+    let mut i = 0;
+
+    while i < 12 {
+        d[i] = number[i].wrapping_sub(b'0');
+
+        if d[i] > 9 {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    let checksum = DOUBLED[d[2] as usize]
+        + d[3]
+        + DOUBLED[d[4] as usize]
+        + d[5]
+        + DOUBLED[d[6] as usize]
+        + d[7]
+        + DOUBLED[d[8] as usize]
+        + d[9]
+        + DOUBLED[d[10] as usize];
+
+    if (10 - checksum % 10) % 10 != d[11] {
+        return false;
+    }
+
+    if d[8] == 0 && d[9] == 0 && d[10] == 0 {
+        return false;
+    }
+
+    let year = d[0] as u16 * 1000 + d[1] as u16 * 100 + d[2] as u16 * 10 + d[3] as u16;
+    let month = d[4] * 10 + d[5];
+    let mut day = d[6] * 10 + d[7];
+
+    if day > 60 {
+        day -= 60;
+    }
+
+    if month < 1 || month > 12 || day == 0 {
+        return false;
+    }
+
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    let days = DAYS_IN_MONTH[month as usize] + (month == 2 && leap) as u8;
+
+    day <= days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "clock")]
+    use crate::Personnummer;
+    use alloc::{vec, vec::Vec};
+
+    const KNOWN: [&[u8; 12]; 2] = [b"199001010017", b"198001613291"];
+
+    const _: () = assert!(is_valid(KNOWN[0]) && is_valid(KNOWN[1]));
+    const _: () = assert!(!is_valid(b"199001010018"));
+
+    #[cfg(feature = "clock")]
+    fn to_fixed(pnr: &str) -> [u8; 12] {
+        pnr.as_bytes().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_validate() {
+        let cases = vec![
+            ("199001010017", true),
+            ("196408233234", true),
+            ("198001613291", true),
+            ("191304012931", true),
+            ("199001010018", false),
+            ("199001010000", false),
+            ("199013010017", false),
+            ("2017022900000", false),
+            ("19900101001a", false),
+        ];
+
+        let numbers = cases
+            .iter()
+            .filter_map(|(pnr, _)| pnr.as_bytes().try_into().ok())
+            .collect::<Vec<[u8; 12]>>();
+        let expected = cases
+            .iter()
+            .filter(|(pnr, _)| pnr.len() == 12)
+            .map(|(_, valid)| *valid)
+            .collect::<Vec<_>>();
+
+        assert_eq!(validate(&numbers), expected);
+        assert_eq!(count_valid(&numbers), 4);
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_same_as_valid() {
+        // Walk through dates and serials covering leap years, month ends and coordination days.
+        for year in [1900, 1999, 2000, 2024] {
+            for month in 0..=13 {
+                for day in [0, 1, 28, 29, 30, 31, 32, 60, 61, 89, 90, 91, 92] {
+                    for serial in [0, 1, 17, 323] {
+                        for control in 0..10 {
+                            let pnr = format!(
+                                "{:04}{:02}{:02}{:03}{}",
+                                year, month, day, serial, control
+                            );
+
+                            let valid = Personnummer::new(&pnr).map(|p| p.valid()).unwrap_or(false);
+
+                            assert_eq!(is_valid(&to_fixed(&pnr)), valid, "{}", pnr);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{Date, Personnummer, COORDINATION_NUMBER};
+
+/// [CoordinationNumber] is a view of a [Personnummer] known to be a coordination number, created
+/// with [Personnummer::as_coordination_number()].
+#[derive(Debug, Clone, Copy)]
+pub struct CoordinationNumber<'a> {
+    pnr: &'a Personnummer,
+}
+
+impl<'a> CoordinationNumber<'a> {
+    /// Returns the underlying [Personnummer].
+    pub fn personnummer(&self) -> &'a Personnummer {
+        self.pnr
+    }
+
+    /// The real birth date with the coordination number removed from the day.
+    pub fn real_birth_date(&self) -> Date {
+        self.pnr.birth_date()
+    }
+
+    /// The day as written in the coordination number, meaning the real day plus 60.
+    pub fn coordination_day(&self) -> u32 {
+        self.pnr.birth_date().day() + COORDINATION_NUMBER
+    }
+}
+
+impl Personnummer {
+    /// Returns a [CoordinationNumber] view if the personal identity number is a coordination
+    /// number.
+    pub fn as_coordination_number(&self) -> Option<CoordinationNumber<'_>> {
+        self.is_coordination_number()
+            .then_some(CoordinationNumber { pnr: self })
+    }
+
+    /// The real birth date. For coordination numbers this is the written day minus 60, for other
+    /// personal identity numbers it's the same as [Personnummer::birth_date()].
+    pub fn real_birth_date(&self) -> Date {
+        self.birth_date()
+    }
+
+    /// The day as written in the personal identity number if it's a coordination number.
+    pub fn coordination_day(&self) -> Option<u32> {
+        self.as_coordination_number().map(|c| c.coordination_day())
+    }
+}
+
+#[cfg(all(test, feature = "clock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordination_number() {
+        let p = Personnummer::new("800161-3291").unwrap();
+        let c = p.as_coordination_number().unwrap();
+
+        assert_eq!(c.real_birth_date(), Date::from_ymd(1980, 1, 1).unwrap());
+        assert_eq!(c.coordination_day(), 61);
+        assert_eq!(p.coordination_day(), Some(61));
+        assert_eq!(p.real_birth_date(), c.real_birth_date());
+    }
+
+    #[test]
+    fn test_not_coordination_number() {
+        let p = Personnummer::new("800101-3294").unwrap();
+
+        assert!(p.as_coordination_number().is_none());
+        assert_eq!(p.coordination_day(), None);
+        assert_eq!(p.real_birth_date(), Date::from_ymd(1980, 1, 1).unwrap());
+    }
+}
@@ -0,0 +1,262 @@
+//! Validate and normalize a column of personal identity numbers in CSV data.
+
+use crate::{parse_valid, FormatOptions, PersonnummerError};
+
+use std::{error::Error, fmt, io};
+
+/// [Column] selects the column holding the personal identity numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    /// The zero based index of the column.
+    Index(usize),
+    /// The name of the column in the header row.
+    Name(String),
+}
+
+/// [CsvOptions] configures [normalize()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    column: Column,
+    has_headers: bool,
+    delimiter: u8,
+    format: FormatOptions,
+    skip_invalid: bool,
+}
+
+impl CsvOptions {
+    /// Returns new [CsvOptions] for the column. By default the first row is a header, the
+    /// delimiter is `,`, numbers are written in the long format and invalid rows are kept as is.
+    pub fn new(column: Column) -> Self {
+        CsvOptions {
+            column,
+            has_headers: true,
+            delimiter: b',',
+            format: FormatOptions::twelve_digits().with_separator(),
+            skip_invalid: false,
+        }
+    }
+
+    /// Set if the first row is a header row.
+    pub fn has_headers(self, has_headers: bool) -> Self {
+        CsvOptions {
+            has_headers,
+            ..self
+        }
+    }
+
+    /// Set the field delimiter.
+    pub fn delimiter(self, delimiter: u8) -> Self {
+        CsvOptions { delimiter, ..self }
+    }
+
+    /// Set the format used for valid personal identity numbers.
+    pub fn format(self, format: FormatOptions) -> Self {
+        CsvOptions { format, ..self }
+    }
+
+    /// Set if rows with an invalid personal identity number should be left out of the output.
+    pub fn skip_invalid(self, skip_invalid: bool) -> Self {
+        CsvOptions {
+            skip_invalid,
+            ..self
+        }
+    }
+}
+
+/// [CsvReport] summarizes the rows processed by [normalize()].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CsvReport {
+    /// The number of data rows, not counting the header.
+    pub rows: usize,
+    /// The number of rows where the personal identity number was valid and normalized.
+    pub normalized: usize,
+    /// The line number and error for each row with an invalid personal identity number.
+    pub errors: Vec<(u64, PersonnummerError)>,
+}
+
+/// [CsvError] is returned from [normalize()] when the CSV data can't be processed.
+#[derive(Debug)]
+pub enum CsvError {
+    /// Reading or writing the CSV data failed.
+    Csv(::csv::Error),
+    /// The selected column doesn't exist.
+    MissingColumn(Column),
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CsvError::Csv(e) => write!(f, "CSV error: {}", e),
+            CsvError::MissingColumn(Column::Index(idx)) => write!(f, "Missing column {}", idx),
+            CsvError::MissingColumn(Column::Name(name)) => write!(f, "Missing column '{}'", name),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Csv(e) => Some(e),
+            CsvError::MissingColumn(_) => None,
+        }
+    }
+}
+
+impl From<::csv::Error> for CsvError {
+    fn from(e: ::csv::Error) -> Self {
+        CsvError::Csv(e)
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> Self {
+        CsvError::Csv(e.into())
+    }
+}
+
+/// Read CSV data, validate the selected column with [parse_valid()] and write the data with valid
+/// personal identity numbers rewritten according to the [CsvOptions]. Rows with invalid numbers
+/// are reported in the returned [CsvReport], rows too short to have the column are invalid the same
+/// way as an empty field. Only a column name missing from the header row is an error.
+pub fn normalize<R: io::Read, W: io::Write>(
+    reader: R,
+    writer: W,
+    options: &CsvOptions,
+) -> Result<CsvReport, CsvError> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .has_headers(options.has_headers)
+        .delimiter(options.delimiter)
+        .flexible(true)
+        .from_reader(reader);
+    let mut writer = ::csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .flexible(true)
+        .from_writer(writer);
+
+    let idx = match &options.column {
+        Column::Index(idx) => *idx,
+        Column::Name(name) if options.has_headers => reader
+            .headers()?
+            .iter()
+            .position(|h| h == name)
+            .ok_or_else(|| CsvError::MissingColumn(options.column.clone()))?,
+        Column::Name(_) => return Err(CsvError::MissingColumn(options.column.clone())),
+    };
+
+    if options.has_headers {
+        writer.write_record(reader.headers()?)?;
+    }
+
+    let mut report = CsvReport::default();
+    let mut formatted = String::with_capacity(13);
+
+    for record in reader.records() {
+        let record = record?;
+        let line = record.position().map_or(0, |p| p.line());
+        report.rows += 1;
+
+        let field = record.get(idx).unwrap_or_default();
+
+        match parse_valid(field.trim()) {
+            Ok(pnr) => {
+                formatted.clear();
+                // Writing to a String never fails.
+                let _ = pnr.format_with_into(options.format, &mut formatted);
+
+                let fields =
+                    record
+                        .iter()
+                        .enumerate()
+                        .map(|(i, f)| if i == idx { formatted.as_str() } else { f });
+
+                writer.write_record(fields)?;
+                report.normalized += 1;
+            }
+            Err(e) => {
+                report.errors.push((line, e));
+
+                if !options.skip_invalid {
+                    writer.write_record(&record)?;
+                }
+            }
+        }
+    }
+
+    writer.flush()?;
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "name,pnr\nA,199001010017\nB,8001613291\nC,19900101-0018\n";
+
+    fn run(options: &CsvOptions) -> (String, CsvReport) {
+        let mut out = Vec::new();
+        let report = normalize(INPUT.as_bytes(), &mut out, options).unwrap();
+
+        (String::from_utf8(out).unwrap(), report)
+    }
+
+    #[test]
+    fn test_normalize() {
+        let (out, report) = run(&CsvOptions::new(Column::Name("pnr".to_string())));
+
+        assert_eq!(
+            out,
+            "name,pnr\nA,19900101-0017\nB,19800161-3291\nC,19900101-0018\n"
+        );
+        assert_eq!(report.rows, 3);
+        assert_eq!(report.normalized, 2);
+        assert_eq!(
+            report.errors,
+            vec![(
+                4,
+                PersonnummerError::InvalidChecksum {
+                    expected: 7,
+                    found: 8
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_normalize_options() {
+        let options = CsvOptions::new(Column::Index(1))
+            .format(FormatOptions::twelve_digits())
+            .skip_invalid(true);
+        let (out, _) = run(&options);
+
+        assert_eq!(out, "name,pnr\nA,199001010017\nB,198001613291\n");
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let options = CsvOptions::new(Column::Name("missing".to_string()));
+        let result = normalize(INPUT.as_bytes(), Vec::new(), &options);
+
+        assert!(matches!(result, Err(CsvError::MissingColumn(_))));
+    }
+
+    #[test]
+    fn test_short_row() {
+        let input = "name,pnr\nA,199001010017\nB\nC,8001613291\n";
+        let mut out = Vec::new();
+        let report = normalize(
+            input.as_bytes(),
+            &mut out,
+            &CsvOptions::new(Column::Name("pnr".to_string())),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "name,pnr\nA,19900101-0017\nB\nC,19800161-3291\n"
+        );
+        assert_eq!(report.rows, 3);
+        assert_eq!(report.normalized, 2);
+        assert_eq!(report.errors, vec![(3, PersonnummerError::WrongLength(0))]);
+    }
+}
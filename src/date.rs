@@ -0,0 +1,249 @@
+use core::fmt;
+
+/// The number of days from 0000-03-01 to 1970-01-01 in the proleptic Gregorian calendar.
+const DAYS_TO_UNIX_EPOCH: i64 = 719_468;
+
+/// [Date] is a calendar date in the proleptic Gregorian calendar. It's the date type used by
+/// [crate::Personnummer] so the crate doesn't depend on any date library. With the `chrono` or
+/// `time` feature it converts to and from `chrono::NaiveDate` or `time::Date`, and all methods
+/// taking a date accept any type that converts into a [Date].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Returns a new [Date] if the year, month and day is a valid date.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Date> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Date { year, month, day })
+    }
+
+    /// Returns the current date, the same date used by methods that don't take a date. With the
+    /// `chrono-tz` feature this is the current date in Sweden, otherwise in UTC.
+    #[cfg(feature = "clock")]
+    pub fn today() -> Date {
+        crate::today()
+    }
+
+    /// The year of the date.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month of the date, starting from 1.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// The day of month of the date, starting from 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Check if the date is in a leap year.
+    pub fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    /// Returns the number of days from the date to the other date, negative if the other date is
+    /// before the date.
+    pub fn days_until(&self, other: Date) -> i64 {
+        other.days_since_unix_epoch() - self.days_since_unix_epoch()
+    }
+
+    /// Returns the number of days since 1970-01-01, based on the algorithm from
+    /// http://howardhinnant.github.io/date_algorithms.html#days_from_civil.
+    fn days_since_unix_epoch(&self) -> i64 {
+        let year = self.year as i64 - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - DAYS_TO_UNIX_EPOCH
+    }
+}
+
+/// Displays the date in ISO 8601 format, e.g. `1990-01-01`.
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Date {
+    fn from(date: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+
+        Date {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+        }
+    }
+}
+
+/// Converting a [Date] outside of the range supported by `chrono`, about 262 000 years around
+/// year 0, panics. The birth date of a [crate::Personnummer] is always in range since the year is
+/// between 0 and 9999, use [chrono::NaiveDate::from_ymd_opt()] for other dates that may be out of
+/// range.
+#[cfg(feature = "chrono")]
+impl From<Date> for chrono::NaiveDate {
+    fn from(date: Date) -> Self {
+        chrono::NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+            .expect("date is in the supported range")
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for Date {
+    fn from(date: time::Date) -> Self {
+        Date {
+            year: date.year(),
+            month: date.month() as u32,
+            day: date.day() as u32,
+        }
+    }
+}
+
+/// Converting a [Date] outside of the range supported by `time`, years -9999 to 9999, returns an
+/// error.
+#[cfg(feature = "time")]
+impl TryFrom<Date> for time::Date {
+    type Error = time::error::ComponentRange;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        let month = time::Month::try_from(date.month as u8)?;
+
+        time::Date::from_calendar_date(date.year, month, date.day as u8)
+    }
+}
+
+/// Zeroizing a [Date] wipes all fields and then resets the date to the Unix epoch so it stays a
+/// valid date.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Date {
+    fn zeroize(&mut self) {
+        self.year.zeroize();
+        self.month.zeroize();
+        self.day.zeroize();
+
+        *self = Date {
+            year: 1970,
+            month: 1,
+            day: 1,
+        };
+    }
+}
+
+/// Check if the year is a leap year.
+fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+}
+
+/// Returns the number of days in the month of the year.
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_from_ymd() {
+        assert!(Date::from_ymd(2000, 2, 29).is_some());
+        assert!(Date::from_ymd(2004, 2, 29).is_some());
+        assert!(Date::from_ymd(1900, 2, 29).is_none());
+        assert!(Date::from_ymd(2001, 2, 29).is_none());
+        assert!(Date::from_ymd(2001, 4, 31).is_none());
+        assert!(Date::from_ymd(2001, 13, 1).is_none());
+        assert!(Date::from_ymd(2001, 1, 0).is_none());
+        assert_eq!(
+            Date::from_ymd(1990, 1, 1).unwrap().to_string(),
+            "1990-01-01"
+        );
+    }
+
+    #[test]
+    fn test_days_until() {
+        let epoch = Date::from_ymd(1970, 1, 1).unwrap();
+
+        assert_eq!(epoch.days_until(epoch), 0);
+        assert_eq!(epoch.days_until(Date::from_ymd(1970, 3, 1).unwrap()), 59);
+        assert_eq!(
+            epoch.days_until(Date::from_ymd(2000, 3, 1).unwrap()),
+            11_017
+        );
+        assert_eq!(
+            epoch.days_until(Date::from_ymd(1900, 1, 1).unwrap()),
+            -25_567
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        use chrono::Datelike;
+
+        let mut date = chrono::NaiveDate::from_ymd_opt(1890, 1, 1).unwrap();
+        let start = Date::from(date);
+
+        while date.year() < 2110 {
+            let converted = Date::from(date);
+
+            assert_eq!(chrono::NaiveDate::from(converted), date);
+            assert_eq!(
+                start.days_until(converted),
+                (date - chrono::NaiveDate::from_ymd_opt(1890, 1, 1).unwrap()).num_days()
+            );
+
+            date = date.succ_opt().unwrap();
+        }
+    }
+
+    #[cfg(all(feature = "time", feature = "clock"))]
+    #[test]
+    fn test_time() {
+        let mut date = time::Date::from_calendar_date(1890, time::Month::January, 1).unwrap();
+        let start = Date::from(date);
+
+        while date.year() < 2110 {
+            let converted = Date::from(date);
+
+            assert_eq!(time::Date::try_from(converted), Ok(date));
+            assert_eq!(
+                start.days_until(converted),
+                (date.to_julian_day() - time::Date::try_from(start).unwrap().to_julian_day())
+                    as i64
+            );
+
+            date = date.next_day().unwrap();
+        }
+
+        let pnr = crate::Personnummer::new("19130401+2931").unwrap();
+        let date = time::Date::from_calendar_date(2013, time::Month::April, 1).unwrap();
+
+        assert_eq!(pnr.age_at(date), 100);
+        assert_eq!(pnr.format_at(date).short(), "130401+2931");
+        assert_eq!(
+            time::Date::try_from(pnr.birth_date()),
+            time::Date::from_calendar_date(1913, time::Month::April, 1)
+        );
+        assert!(time::Date::try_from(Date::from_ymd(10_000, 1, 1).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,318 @@
+//! C bindings for linking the crate into C and C++ programs. All functions take the personal
+//! identity number as a NUL terminated UTF-8 string and are strict, meaning that numbers with an
+//! invalid checksum are treated as invalid. Nothing is allocated, strings are written to buffers
+//! owned by the caller.
+//!
+//! Build a static or dynamic library with the `ffi` feature and include `include/personnummer.h`,
+//! which is generated with `cbindgen`.
+//!
+//! ```sh
+//! cargo rustc --lib --release --features ffi --crate-type staticlib
+//! cbindgen --config cbindgen.toml --output include/personnummer.h
+//! ```
+
+use crate::{parse_valid, Personnummer, PersonnummerError};
+
+use std::{
+    ffi::{c_char, c_int, CStr},
+    fmt,
+};
+
+/// The buffer size needed for the long format including the NUL terminator.
+pub const PNR_LONG_LEN: usize = 14;
+
+/// The buffer size needed for the short format including the NUL terminator.
+pub const PNR_SHORT_LEN: usize = 12;
+
+/// [PnrError] is the result of the C functions, see [PersonnummerError] for the parse errors.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PnrError {
+    /// No error.
+    Ok = 0,
+    /// A pointer argument is NULL.
+    NullPointer,
+    /// The input isn't valid UTF-8.
+    InvalidUtf8,
+    /// The output buffer is too small.
+    BufferTooSmall,
+    InvalidInput,
+    WrongLength,
+    InvalidSeparator,
+    NonDigit,
+    InvalidMonth,
+    InvalidDay,
+    MissingControlDigit,
+    InvalidSerial,
+    InvalidChecksum,
+    FutureBirthDate,
+    InvalidCoordinationDay,
+}
+
+impl From<PersonnummerError> for PnrError {
+    fn from(e: PersonnummerError) -> Self {
+        match e {
+            PersonnummerError::InvalidInput => PnrError::InvalidInput,
+            PersonnummerError::WrongLength(_) => PnrError::WrongLength,
+            PersonnummerError::InvalidSeparator { .. } => PnrError::InvalidSeparator,
+            PersonnummerError::NonDigit { .. } => PnrError::NonDigit,
+            PersonnummerError::InvalidMonth(_) => PnrError::InvalidMonth,
+            PersonnummerError::InvalidDay(_) => PnrError::InvalidDay,
+            PersonnummerError::MissingControlDigit => PnrError::MissingControlDigit,
+            PersonnummerError::InvalidSerial => PnrError::InvalidSerial,
+            PersonnummerError::InvalidChecksum { .. } => PnrError::InvalidChecksum,
+            PersonnummerError::FutureBirthDate => PnrError::FutureBirthDate,
+            PersonnummerError::InvalidCoordinationDay(_) => PnrError::InvalidCoordinationDay,
+        }
+    }
+}
+
+/// Check if the input is a valid personal identity number. Returns false for NULL and input that
+/// isn't valid UTF-8.
+///
+/// # Safety
+///
+/// `pnr` must be NULL or point to a NUL terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn pnr_valid(pnr: *const c_char) -> bool {
+    parse(pnr).is_ok()
+}
+
+/// Validate the input and return why it isn't a valid personal identity number, or
+/// `PNR_ERROR_OK` if it's valid.
+///
+/// # Safety
+///
+/// `pnr` must be NULL or point to a NUL terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn pnr_validate(pnr: *const c_char) -> PnrError {
+    match parse(pnr) {
+        Ok(_) => PnrError::Ok,
+        Err(e) => e,
+    }
+}
+
+/// Write the long format, e.g. `19900101-0017`, as a NUL terminated string to `out`. The buffer
+/// must be at least `PNR_LONG_LEN` bytes.
+///
+/// # Safety
+///
+/// `pnr` must be NULL or point to a NUL terminated string and `out` must be NULL or point to at
+/// least `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn pnr_format_long(
+    pnr: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> PnrError {
+    write_to(pnr, out, out_len, |p, s| p.format_long_into(s))
+}
+
+/// Write the short format, e.g. `900101-0017`, as a NUL terminated string to `out`. The buffer
+/// must be at least `PNR_SHORT_LEN` bytes.
+///
+/// # Safety
+///
+/// `pnr` must be NULL or point to a NUL terminated string and `out` must be NULL or point to at
+/// least `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn pnr_format_short(
+    pnr: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> PnrError {
+    write_to(pnr, out, out_len, |p, s| p.format_short_into(s))
+}
+
+/// Write the current age of the person to `age`.
+///
+/// # Safety
+///
+/// `pnr` must be NULL or point to a NUL terminated string and `age` must be NULL or point to a
+/// writable `uint32_t`.
+#[no_mangle]
+pub unsafe extern "C" fn pnr_age(pnr: *const c_char, age: *mut u32) -> PnrError {
+    if age.is_null() {
+        return PnrError::NullPointer;
+    }
+
+    match parse(pnr) {
+        Ok(p) => {
+            *age = p.get_age() as u32;
+            PnrError::Ok
+        }
+        Err(e) => e,
+    }
+}
+
+/// Returns a static NUL terminated English description of the error, or `Unknown error` if the
+/// value isn't a `PnrError`.
+#[no_mangle]
+pub extern "C" fn pnr_error_message(error: c_int) -> *const c_char {
+    let message: &'static CStr = match error {
+        e if e == PnrError::Ok as c_int => c"No error",
+        e if e == PnrError::NullPointer as c_int => c"Null pointer",
+        e if e == PnrError::InvalidUtf8 as c_int => c"Invalid UTF-8",
+        e if e == PnrError::BufferTooSmall as c_int => c"Buffer too small",
+        e if e == PnrError::InvalidInput as c_int => c"Invalid format",
+        e if e == PnrError::WrongLength as c_int => c"Invalid length, expected 10 or 12 digits",
+        e if e == PnrError::InvalidSeparator as c_int => c"Invalid separator",
+        e if e == PnrError::NonDigit as c_int => c"Invalid character",
+        e if e == PnrError::InvalidMonth as c_int => c"Invalid month",
+        e if e == PnrError::InvalidDay as c_int => c"Invalid day",
+        e if e == PnrError::MissingControlDigit as c_int => c"Missing control digit",
+        e if e == PnrError::InvalidSerial as c_int => c"Invalid serial",
+        e if e == PnrError::InvalidChecksum as c_int => c"Invalid checksum",
+        e if e == PnrError::FutureBirthDate as c_int => c"Birth date is in the future",
+        e if e == PnrError::InvalidCoordinationDay as c_int => c"Invalid coordination day",
+        _ => c"Unknown error",
+    };
+
+    message.as_ptr()
+}
+
+/// Parse a C string with [parse_valid()].
+unsafe fn parse(pnr: *const c_char) -> Result<Personnummer, PnrError> {
+    if pnr.is_null() {
+        return Err(PnrError::NullPointer);
+    }
+
+    let pnr = CStr::from_ptr(pnr)
+        .to_str()
+        .map_err(|_| PnrError::InvalidUtf8)?;
+
+    parse_valid(pnr).map_err(PnrError::from)
+}
+
+/// Parse the input and write the formatted result as a NUL terminated string to the buffer.
+unsafe fn write_to<F>(pnr: *const c_char, out: *mut c_char, out_len: usize, format: F) -> PnrError
+where
+    F: Fn(&Personnummer, &mut Buffer) -> fmt::Result,
+{
+    if out.is_null() {
+        return PnrError::NullPointer;
+    }
+
+    let pnr = match parse(pnr) {
+        Ok(p) => p,
+        Err(e) => return e,
+    };
+
+    let mut buffer = Buffer {
+        out: std::slice::from_raw_parts_mut(out.cast::<u8>(), out_len),
+        len: 0,
+    };
+
+    if format(&pnr, &mut buffer).is_err() {
+        return PnrError::BufferTooSmall;
+    }
+
+    buffer.out[buffer.len] = 0;
+
+    PnrError::Ok
+}
+
+/// [Buffer] writes formatted strings to a buffer owned by the caller, leaving room for the NUL
+/// terminator. Writing fails if the buffer is too small.
+struct Buffer<'a> {
+    out: &'a mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for Buffer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+
+        if end >= self.out.len() {
+            return Err(fmt::Error);
+        }
+
+        self.out[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ffi::CString;
+
+    fn format(
+        f: unsafe extern "C" fn(*const c_char, *mut c_char, usize) -> PnrError,
+        pnr: &str,
+        len: usize,
+    ) -> Result<String, PnrError> {
+        let pnr = CString::new(pnr).unwrap();
+        let mut buf = vec![0 as c_char; len];
+
+        match unsafe { f(pnr.as_ptr(), buf.as_mut_ptr(), len) } {
+            PnrError::Ok => Ok(unsafe { CStr::from_ptr(buf.as_ptr()) }
+                .to_str()
+                .unwrap()
+                .to_string()),
+            e => Err(e),
+        }
+    }
+
+    #[test]
+    fn test_valid() {
+        let valid = CString::new("900101-0017").unwrap();
+        let invalid = CString::new("900101-0018").unwrap();
+
+        unsafe {
+            assert!(pnr_valid(valid.as_ptr()));
+            assert!(!pnr_valid(invalid.as_ptr()));
+            assert!(!pnr_valid(std::ptr::null()));
+            assert_eq!(pnr_validate(valid.as_ptr()), PnrError::Ok);
+            assert_eq!(pnr_validate(invalid.as_ptr()), PnrError::InvalidChecksum);
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            format(pnr_format_long, "9001010017", PNR_LONG_LEN).unwrap(),
+            "19900101-0017"
+        );
+        assert_eq!(
+            format(pnr_format_short, "19900101-0017", PNR_SHORT_LEN).unwrap(),
+            "900101-0017"
+        );
+        assert_eq!(
+            format(pnr_format_long, "9001010017", PNR_LONG_LEN - 1),
+            Err(PnrError::BufferTooSmall)
+        );
+        assert_eq!(
+            format(pnr_format_long, "900101-001", PNR_LONG_LEN),
+            Err(PnrError::MissingControlDigit)
+        );
+    }
+
+    #[test]
+    fn test_age() {
+        let pnr = CString::new("19900101-0017").unwrap();
+        let mut age = 0;
+
+        unsafe {
+            assert_eq!(pnr_age(pnr.as_ptr(), &mut age), PnrError::Ok);
+            assert_eq!(
+                pnr_age(pnr.as_ptr(), std::ptr::null_mut()),
+                PnrError::NullPointer
+            );
+        }
+
+        assert!(age >= 35);
+
+        let message = |error| unsafe { CStr::from_ptr(pnr_error_message(error)) };
+
+        assert_eq!(
+            message(PnrError::InvalidChecksum as c_int).to_str(),
+            Ok("Invalid checksum")
+        );
+        assert_eq!(message(-1).to_str(), Ok("Unknown error"));
+        assert_eq!(message(1_000).to_str(), Ok("Unknown error"));
+    }
+}
@@ -1,51 +1,177 @@
-#[macro_use]
-extern crate lazy_static;
-
-use chrono::{Datelike, NaiveDate, Utc};
-use regex::{Match, Regex};
-
-use std::{convert::TryFrom, error::Error, fmt};
-
-lazy_static! {
-    static ref PNR_REGEX: Regex = Regex::new(
-        r"(?x)
-        ^                    # Starts with
-        (?P<century>\d{2})?  # Maybe the century
-        (?P<year>\d{2})      # Year with two digits
-        (?P<month>\d{2})     # Month
-        (?P<day>\d{2})       # Day
-        (?P<divider>[-+]?)?  # Divider can be - or +
-        (?P<number>\d{3})    # At least three digits
-        (?P<control>\d?)     # And an optional control digit
-        $"
-    )
-    .unwrap();
-}
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// Allow the `pnr!` macro, which expands to `::personnummer` paths, to be used within the crate.
+#[cfg(all(test, feature = "macros"))]
+extern crate self as personnummer;
+
+#[cfg(feature = "clock")]
+use chrono::Utc;
+
+use alloc::string::String;
+use core::fmt;
+
+mod age;
+#[cfg(feature = "clock")]
+pub mod bankid;
+#[cfg(feature = "clock")]
+mod batch;
+pub mod bulk;
+mod coordination;
+#[cfg(feature = "csv")]
+pub mod csv;
+mod date;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod locale;
+mod minimal;
+pub mod mrz;
+#[cfg(feature = "online")]
+pub mod online;
+mod partial;
+#[cfg(feature = "pseudonymize")]
+mod pseudonymize;
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "region")]
+mod region;
+#[cfg(feature = "clock")]
+pub mod scan;
+mod separator;
+pub mod set;
+pub mod stats;
+mod suggest;
+#[cfg(feature = "clock")]
+mod test_numbers;
+#[cfg(feature = "testdata")]
+pub mod testdata;
+mod validate;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use age::{Age, LeapDayPolicy};
+#[cfg(feature = "async")]
+pub use batch::validate_stream;
+#[cfg(feature = "rayon")]
+pub use batch::{par_validate_all, par_validate_all_summary};
+#[cfg(feature = "clock")]
+pub use batch::{read_lines, validate_all, validate_all_summary, BatchSummary, Line, Lines};
+pub use coordination::CoordinationNumber;
+pub use date::Date;
+pub use locale::Locale;
+pub use minimal::{AgeBand, Minimal, Profile};
+#[cfg(feature = "macros")]
+pub use personnummer_macros::pnr;
+#[cfg(feature = "pseudonymize")]
+pub use pseudonymize::PseudonymizedId;
+#[cfg(feature = "region")]
+pub use region::BirthRegion;
+pub use separator::Separator;
+pub use set::PersonnummerSet;
+#[cfg(feature = "clock")]
+pub use test_numbers::TestNumbers;
+pub use validate::ValidationReport;
 
 /// The extra value added to coordination numbers.
 const COORDINATION_NUMBER: u32 = 60;
 
-#[derive(Debug)]
+/// The largest value a personal identity number can be packed as, see [Personnummer::to_u64()].
+const MAX_PACKED: u64 = 999_999_999_999;
+
+/// The largest year that fits in the four digit year of the long format.
+const MAX_YEAR: i32 = 9999;
+
+/// [PersonnummerError] describes why a personal identity number couldn't be parsed. Offsets are
+/// byte offsets in the input. More variants may be added without a major version bump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum PersonnummerError {
+    /// The input is invalid for a reason not covered by a more specific error.
     InvalidInput,
-    InvalidDate,
+    /// The input doesn't contain 10 or 12 digits, holds the number of digits found.
+    WrongLength(usize),
+    /// The separator isn't `-` or `+` or isn't placed between the date and the serial.
+    InvalidSeparator { offset: usize, found: char },
+    /// The input contains a character that isn't a digit or separator.
+    NonDigit { offset: usize, found: char },
+    /// The month isn't between 1 and 12, holds the month as written.
+    InvalidMonth(u32),
+    /// The day doesn't exist in the month, holds the day as written.
+    InvalidDay(u32),
+    /// The input is one digit short of a complete personal identity number.
+    MissingControlDigit,
+    /// The serial is 000 which is never used.
+    InvalidSerial,
+    /// The control digit doesn't match the Luhn checksum.
+    InvalidChecksum { expected: u8, found: u8 },
+    /// The birth date is in the future.
+    FutureBirthDate,
+    /// The day is above 60 but isn't a valid coordination day, holds the day as written. The day of
+    /// a coordination number is the day of the month plus 60, i.e. 61 to 91.
+    InvalidCoordinationDay(u32),
 }
 
 impl fmt::Display for PersonnummerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             PersonnummerError::InvalidInput => write!(f, "Invalid format"),
-            PersonnummerError::InvalidDate => write!(f, "Invalid date"),
+            PersonnummerError::WrongLength(digits) => {
+                write!(
+                    f,
+                    "Invalid length, expected 10 or 12 digits but got {}",
+                    digits
+                )
+            }
+            PersonnummerError::InvalidSeparator { offset, found } => {
+                write!(f, "Invalid separator '{}' at position {}", found, offset)
+            }
+            PersonnummerError::NonDigit { offset, found } => {
+                write!(f, "Invalid character '{}' at position {}", found, offset)
+            }
+            PersonnummerError::InvalidMonth(month) => write!(f, "Invalid month {}", month),
+            PersonnummerError::InvalidDay(day) => write!(f, "Invalid day {}", day),
+            PersonnummerError::MissingControlDigit => write!(f, "Missing control digit"),
+            PersonnummerError::InvalidSerial => write!(f, "Invalid serial"),
+            PersonnummerError::InvalidChecksum { expected, found } => write!(
+                f,
+                "Invalid checksum, expected control digit {} but got {}",
+                expected, found
+            ),
+            PersonnummerError::FutureBirthDate => write!(f, "Birth date is in the future"),
+            PersonnummerError::InvalidCoordinationDay(day) => {
+                write!(f, "Invalid coordination day {}", day)
+            }
         }
     }
 }
 
-impl Error for PersonnummerError {}
+#[cfg(feature = "std")]
+impl std::error::Error for PersonnummerError {}
+
+/// [Gender] is the legal gender of the person holding the personal identity number, encoded in
+/// the second to last digit of the serial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Gender {
+    Female,
+    Male,
+}
+
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Gender::Female => write!(f, "female"),
+            Gender::Male => write!(f, "male"),
+        }
+    }
+}
 
 #[allow(dead_code)]
 /// [Personnummer] holds relevant data to check for valid personal identity numbers.
 pub struct Personnummer {
-    date: chrono::NaiveDate,
+    date: Date,
     serial: u32,
     control: u8,
     divider: char,
@@ -72,48 +198,391 @@ impl FormattedPersonnummer {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for FormattedPersonnummer {
+    fn zeroize(&mut self) {
+        self.long.zeroize();
+        self.short.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for FormattedPersonnummer {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for FormattedPersonnummer {}
+
+/// [Parts] are the components of a [Personnummer], created with [Personnummer::into_parts()].
+/// The day is the day of the birth date, also for coordination numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parts {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub serial: u32,
+    pub control: u8,
+    pub coordination: bool,
+}
+
+/// [FormatOptions] describes how a [Personnummer] should be formatted with
+/// [Personnummer::format_with()]. Start with either [FormatOptions::ten_digits()] or
+/// [FormatOptions::twelve_digits()] and add a separator with [FormatOptions::with_separator()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    century: bool,
+    separator: bool,
+}
+
+impl FormatOptions {
+    /// Format with a two digit year, e.g. `9001010017`.
+    pub fn ten_digits() -> Self {
+        FormatOptions {
+            century: false,
+            separator: false,
+        }
+    }
+
+    /// Format with a four digit year, e.g. `199001010017`.
+    pub fn twelve_digits() -> Self {
+        FormatOptions {
+            century: true,
+            separator: false,
+        }
+    }
+
+    /// Include the separator between the date and the serial, e.g. `19900101-0017`.
+    pub fn with_separator(self) -> Self {
+        FormatOptions {
+            separator: true,
+            ..self
+        }
+    }
+
+    /// Omit the separator between the date and the serial, e.g. `199001010017`.
+    pub fn without_separator(self) -> Self {
+        FormatOptions {
+            separator: false,
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "clock")]
 impl TryFrom<&str> for Personnummer {
     type Error = PersonnummerError;
 
     fn try_from(pnr: &str) -> Result<Self, PersonnummerError> {
-        let caps = PNR_REGEX
-            .captures(pnr)
-            .ok_or(PersonnummerError::InvalidInput)?;
+        Personnummer::parse_at(pnr, today).map(|(pnr, _)| pnr)
+    }
+}
+
+/// Displays the long format of the [Personnummer] without allocating, see
+/// [Personnummer::format_long_into()].
+impl fmt::Display for Personnummer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format_long_into(f)
+    }
+}
+
+/// Zeroizing a [Personnummer] resets the date to the Unix epoch and all other fields to zero.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Personnummer {
+    fn zeroize(&mut self) {
+        self.date.zeroize();
+        self.serial.zeroize();
+        self.control.zeroize();
+        self.divider.zeroize();
+        self.coordination.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Personnummer {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Personnummer {}
+
+/// Compare the canonical form, see [Personnummer::to_u64()], in constant time so the comparison
+/// doesn't leak how much of a personal identity number used as a credential matched. The
+/// separator and the format it was parsed from don't affect equality.
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for Personnummer {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.to_u64().ct_eq(&other.to_u64())
+    }
+}
+
+/// Debug output never contains the serial and control digit to not leak personal data to logs, see
+/// [Personnummer::masked()].
+impl fmt::Debug for Personnummer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Personnummer")
+            .field(&format_args!("{}", self.masked()))
+            .finish()
+    }
+}
 
-        let century = match caps.name("century") {
-            Some(m) => m.as_str().parse::<u32>().unwrap_or(19) * 100,
-            None => 1900,
+/// [Masked] displays the long format of a [Personnummer] with the serial and control digit
+/// replaced, e.g. `19900101-XXXX`. Created with [Personnummer::masked()].
+pub struct Masked<'a> {
+    pnr: &'a Personnummer,
+}
+
+impl fmt::Display for Masked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}{:02}{:02}-XXXX",
+            self.pnr.date.year(),
+            self.pnr.date.month(),
+            self.pnr.day_or_coordination()
+        )
+    }
+}
+
+impl Personnummer {
+    /// Returns a new instance of a [Personnummer]. Returns an error for invalid input and dates
+    /// but not for invalid personal identity numbers. Use [Personnummer::valid()] to check
+    /// validity or [Personnummer::parse()] to only get valid personal identity numbers.
+    #[cfg(feature = "clock")]
+    pub fn new(pnr: &str) -> Result<Personnummer, PersonnummerError> {
+        Personnummer::try_from(pnr)
+    }
+
+    /// Same as [Personnummer::new()] but also returns an error if the personal identity number
+    /// isn't valid, meaning that any [Personnummer] returned is guaranteed to be valid.
+    #[cfg(feature = "clock")]
+    pub fn parse(pnr: &str) -> Result<Personnummer, PersonnummerError> {
+        let pnr = Personnummer::try_from(pnr)?;
+        pnr.validate_checksum()?;
+
+        Ok(pnr)
+    }
+
+    /// Same as [Personnummer::new()] but the century for personal identity numbers without one is
+    /// resolved relative to the given date instead of the current date. Without a century the
+    /// person is assumed to be less than 100 years old at the date, or at least 100 years old if
+    /// the divider is `+`.
+    pub fn new_at(pnr: &str, date: impl Into<Date>) -> Result<Personnummer, PersonnummerError> {
+        Personnummer::parse_at(pnr, || date.into()).map(|(pnr, _)| pnr)
+    }
+
+    /// Same as [Personnummer::parse()] but returns every plausible interpretation of a personal
+    /// identity number without century instead of picking one, see
+    /// [Personnummer::parse_ambiguous_at()].
+    #[cfg(feature = "clock")]
+    pub fn parse_ambiguous(pnr: &str) -> Result<alloc::vec::Vec<Personnummer>, PersonnummerError> {
+        Personnummer::parse_ambiguous_at(pnr, today())
+    }
+
+    /// Returns every plausible interpretation of a valid personal identity number relative to the
+    /// given date, so the caller can disambiguate with other information, e.g. `240101-…` can be
+    /// someone born 1924 or 2024. Use [Personnummer::century()] to get the assumed century of
+    /// each.
+    ///
+    /// Without a century the candidates are the person younger than 100 years and the person
+    /// between 100 and 199 years old, skipping birth dates after the date and dates that don't
+    /// exist in that century. The interpretation matching the divider, the same as
+    /// [Personnummer::new_at()] picks, comes first. With a century there is only one candidate.
+    pub fn parse_ambiguous_at(
+        pnr: &str,
+        date: impl Into<Date>,
+    ) -> Result<alloc::vec::Vec<Personnummer>, PersonnummerError> {
+        let date = date.into();
+        let parsed = Personnummer::parse_at(pnr, || date);
+
+        // A leap day may only exist in one of the centuries, anything else is invalid for all.
+        let (year, plus) = match (&parsed, pnr.len()) {
+            (
+                Ok(_)
+                | Err(
+                    PersonnummerError::InvalidDay(_) | PersonnummerError::InvalidCoordinationDay(_),
+                ),
+                10 | 11,
+            ) => (
+                digits_to_u32(&pnr.as_bytes()[..2]) as i32,
+                pnr.as_bytes()[6] == b'+',
+            ),
+            _ => {
+                let (pnr, _) = parsed?;
+                pnr.validate_checksum()?;
+
+                return Ok(alloc::vec![pnr]);
+            }
         };
 
-        let match_to_u32 =
-            |m: Option<Match<'_>>| -> u32 { m.unwrap().as_str().parse::<u32>().unwrap_or(0) };
-
-        let year = match_to_u32(caps.name("year"));
-        let month = match_to_u32(caps.name("month"));
-        let day = match_to_u32(caps.name("day"));
-        let serial = match_to_u32(caps.name("number"));
-
-        let control = caps
-            .name("control")
-            .unwrap()
-            .as_str()
-            .parse::<u8>()
-            .unwrap_or(0);
-
-        let divider = caps
-            .name("divider")
-            .unwrap()
-            .as_str()
-            .parse::<char>()
-            .unwrap_or('\0');
-
-        let date = match NaiveDate::from_ymd_opt(
-            (century + year) as i32,
-            month,
-            day % COORDINATION_NUMBER,
-        ) {
+        let youngest = date.year() - (date.year().rem_euclid(100) - year).rem_euclid(100);
+        let mut years = [youngest, youngest - 100];
+
+        if plus {
+            years.reverse();
+        }
+
+        let mut candidates = years
+            .iter()
+            .filter_map(|year| {
+                let with_century = alloc::format!("{:02}{}", year.div_euclid(100), pnr);
+
+                Personnummer::parse_at(&with_century, || date).ok()
+            })
+            .map(|(pnr, _)| pnr)
+            .filter(|pnr| pnr.birth_date() <= date)
+            .collect::<alloc::vec::Vec<_>>();
+
+        if candidates.is_empty() {
+            candidates.push(parsed?.0);
+        }
+
+        candidates[0].validate_checksum()?;
+
+        Ok(candidates)
+    }
+
+    /// Parse a personal identity number in a single pass over the input. The Luhn checksum is
+    /// calculated while scanning and returned together with the [Personnummer]. The reference
+    /// date is only resolved if the century isn't part of the input.
+    fn parse_at(
+        pnr: &str,
+        date: impl FnOnce() -> Date,
+    ) -> Result<(Personnummer, u8), PersonnummerError> {
+        let bytes = pnr.as_bytes();
+        let len = bytes.len();
+
+        // The date is 6 or 8 digits followed by an optional divider and a 4 digit serial and
+        // control digit.
+        let (date_part, divider, serial_part) = match len {
+            10 | 12 => (&bytes[..len - 4], '\0', &bytes[len - 4..]),
+            11 | 13 if matches!(bytes[len - 5], b'-' | b'+') => {
+                (&bytes[..len - 5], bytes[len - 5] as char, &bytes[len - 4..])
+            }
+            _ => return Err(diagnose(pnr)),
+        };
+
+        // The checksum is calculated over the ten digit format without the control digit.
+        let century_digits = date_part.len() - 6;
+        let mut checksum = 0;
+
+        for (idx, b) in date_part.iter().chain(serial_part).enumerate() {
+            if !b.is_ascii_digit() {
+                return Err(diagnose(pnr));
+            }
+
+            if let Some(luhn_idx) = idx.checked_sub(century_digits).filter(|i| *i < 9) {
+                checksum += luhn_value(luhn_idx, u32::from(b - b'0'));
+            }
+        }
+
+        let (century, ymd) = match date_part.len() {
+            8 => (Some(digits_to_u32(&date_part[..2]) as i32), &date_part[2..]),
+            _ => (None, date_part),
+        };
+
+        let year = digits_to_u32(&ymd[..2]) as i32;
+        let month = digits_to_u32(&ymd[2..4]);
+        let day = digits_to_u32(&ymd[4..]);
+        let serial = digits_to_u32(&serial_part[..3]);
+        let control = serial_part[3] - b'0';
+
+        let full_year = match century {
+            Some(century) => century * 100 + year,
+            None => {
+                let date = date();
+                let base_year = if divider == '+' {
+                    date.year().checked_sub(100)
+                } else {
+                    Some(date.year())
+                };
+
+                // The reference date can be any date, use checked arithmetic so extreme dates
+                // return an error instead of overflowing and reject birth years that don't fit in
+                // the long format.
+                base_year
+                    .and_then(|base| {
+                        base.checked_sub((base.rem_euclid(100) - year).rem_euclid(100))
+                    })
+                    .filter(|year| (0..=MAX_YEAR).contains(year))
+                    .ok_or(PersonnummerError::InvalidInput)?
+            }
+        };
+
+        let pnr = Personnummer::from_parts(full_year, month, day, serial, control, divider)?;
+
+        Ok((pnr, luhn_control(checksum)))
+    }
+
+    /// Returns a new instance of a [Personnummer] from a value created with
+    /// [Personnummer::to_u64()]. The value is validated the same way as when parsing a string so
+    /// values outside of the 12 digit range or with an invalid date will return an error.
+    pub fn from_u64(value: u64) -> Result<Personnummer, PersonnummerError> {
+        if value > MAX_PACKED {
+            return Err(PersonnummerError::InvalidInput);
+        }
+
+        let control = (value % 10) as u8;
+        let serial = (value / 10 % 1_000) as u32;
+        let day = (value / 10_000 % 100) as u32;
+        let month = (value / 1_000_000 % 100) as u32;
+        let year = (value / 100_000_000) as i32;
+
+        Personnummer::from_parts(year, month, day, serial, control, '\0')
+    }
+
+    /// Returns the personal identity number packed as an integer. The integer is the long format
+    /// without divider, e.g. `19900101-0017` is packed as `199001010017`, which means that
+    /// ordering of packed values is the same as the ordering of the long format. Use
+    /// [Personnummer::from_u64()] to get the [Personnummer] back.
+    pub fn to_u64(&self) -> u64 {
+        self.date.year() as u64 * 100_000_000
+            + self.date.month() as u64 * 1_000_000
+            + self.day_or_coordination() as u64 * 10_000
+            + self.serial as u64 * 10
+            + self.control as u64
+    }
+
+    /// Create a [Personnummer] from a packed value validated at compile time by the `pnr!` macro
+    /// with [bulk::is_valid()].
+    #[cfg(feature = "macros")]
+    #[doc(hidden)]
+    pub fn __from_macro(value: u64, divider: char) -> Personnummer {
+        let mut pnr = Personnummer::from_u64(value).expect("validated by the pnr! macro");
+        pnr.divider = divider;
+
+        pnr
+    }
+
+    /// Create a [Personnummer] from already parsed parts where the day may be a coordination day.
+    fn from_parts(
+        year: i32,
+        month: u32,
+        day: u32,
+        serial: u32,
+        control: u8,
+        divider: char,
+    ) -> Result<Personnummer, PersonnummerError> {
+        if !(1..=12).contains(&month) {
+            return Err(PersonnummerError::InvalidMonth(month));
+        }
+
+        let coordination = day > COORDINATION_NUMBER;
+        let real_day = if coordination {
+            day - COORDINATION_NUMBER
+        } else {
+            day
+        };
+
+        let date = match Date::from_ymd(year, month, real_day) {
             Some(date) => date,
-            None => return Err(PersonnummerError::InvalidDate),
+            None if coordination => return Err(PersonnummerError::InvalidCoordinationDay(day)),
+            None => return Err(PersonnummerError::InvalidDay(day)),
         };
 
         Ok(Personnummer {
@@ -121,84 +590,206 @@ impl TryFrom<&str> for Personnummer {
             serial,
             control,
             divider,
-            coordination: (day > 31),
+            coordination,
         })
     }
-}
 
-impl Personnummer {
-    /// Returns a new instance of a [Personnummer]. Panics for invalid dates but not for invalid
-    /// personal identity numbers. Use [Personnummer::valid()] to check validity.
-    pub fn new(pnr: &str) -> Result<Personnummer, PersonnummerError> {
-        Personnummer::try_from(pnr)
+    /// Returns a [FormattedPersonnummer] from a [Personnummer] which can be used to display a
+    /// normalized version of the [Personnummer]. The short format is relative to the current date,
+    /// see [Personnummer::format_at()]. This allocates both formats, use
+    /// [Personnummer::format_long_into()] or [Personnummer::format_short_into()] to write a format
+    /// without allocating.
+    #[cfg(feature = "clock")]
+    pub fn format(&self) -> FormattedPersonnummer {
+        self.format_at(today())
     }
 
-    /// Same as [Personnummer::new()] but returns an [Option] instead of panicing on invalid dates.
-    pub fn parse(pnr: &str) -> Result<Personnummer, PersonnummerError> {
-        Personnummer::try_from(pnr)
+    /// Returns a [FormattedPersonnummer] where the short format is relative to the given date. The
+    /// separator in the short format is `+` if the person is 100 years or older at the date and
+    /// `-` otherwise, which means that an archived number can be rendered the way it was written at
+    /// the time.
+    pub fn format_at(&self, date: impl Into<Date>) -> FormattedPersonnummer {
+        let date = date.into();
+        let mut long = String::with_capacity(13);
+        let mut short = String::with_capacity(11);
+
+        // Writing to a String never fails.
+        let _ = self.write_at(
+            FormatOptions::twelve_digits().with_separator(),
+            date,
+            &mut long,
+        );
+        let _ = self.write_at(
+            FormatOptions::ten_digits().with_separator(),
+            date,
+            &mut short,
+        );
+
+        FormattedPersonnummer { long, short }
     }
 
-    /// Returns a [FormattedPersonnummer] from a [Personnummer] which can be used to display a
-    /// normalized version of the [Personnummer].
-    pub fn format(&self) -> FormattedPersonnummer {
-        let day = self.date.day();
-        let day_or_coordination = if self.coordination {
-            day + COORDINATION_NUMBER
+    /// Returns the [Personnummer] formatted according to the [FormatOptions]. A separator in a ten
+    /// digit format is relative to the current date, see [Personnummer::format_with_at()].
+    #[cfg(feature = "clock")]
+    pub fn format_with(&self, options: FormatOptions) -> String {
+        self.format_with_at(options, today())
+    }
+
+    /// Returns the [Personnummer] formatted according to the [FormatOptions] where a separator in
+    /// a ten digit format is relative to the given date.
+    pub fn format_with_at(&self, options: FormatOptions, date: impl Into<Date>) -> String {
+        let mut s = String::with_capacity(13);
+
+        // Writing to a String never fails.
+        let _ = self.format_with_into_at(options, date, &mut s);
+
+        s
+    }
+
+    /// Write the [Personnummer] formatted according to the [FormatOptions] to any [fmt::Write]
+    /// without allocating. A separator in a ten digit format is relative to the current date, see
+    /// [Personnummer::format_at()].
+    #[cfg(feature = "clock")]
+    pub fn format_with_into<W: fmt::Write>(
+        &self,
+        options: FormatOptions,
+        w: &mut W,
+    ) -> fmt::Result {
+        self.format_with_into_at(options, today(), w)
+    }
+
+    /// Same as [Personnummer::format_with_into()] but a separator in a ten digit format is
+    /// relative to the given date.
+    pub fn format_with_into_at<W: fmt::Write>(
+        &self,
+        options: FormatOptions,
+        date: impl Into<Date>,
+        w: &mut W,
+    ) -> fmt::Result {
+        self.write_at(options, date.into(), w)
+    }
+
+    /// Returns a view of the [Personnummer] that displays the long format with the serial and
+    /// control digit masked, e.g. `19900101-XXXX`, which is safe to use in logs.
+    pub fn masked(&self) -> Masked<'_> {
+        Masked { pnr: self }
+    }
+
+    /// Write the long format, e.g. `19900101-0017`, of the [Personnummer] to any [fmt::Write]
+    /// without allocating.
+    pub fn format_long_into<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        // The separator in the long format is always `-` so the date doesn't matter.
+        self.write_at(
+            FormatOptions::twelve_digits().with_separator(),
+            self.date,
+            w,
+        )
+    }
+
+    /// Write the short format, e.g. `900101-0017`, of the [Personnummer] to any [fmt::Write]
+    /// without allocating.
+    #[cfg(feature = "clock")]
+    pub fn format_short_into<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        self.format_with_into(FormatOptions::ten_digits().with_separator(), w)
+    }
+
+    /// Write the [Personnummer] formatted according to the [FormatOptions] where the separator in
+    /// a ten digit format is relative to the given date.
+    fn write_at<W: fmt::Write>(
+        &self,
+        options: FormatOptions,
+        date: Date,
+        w: &mut W,
+    ) -> fmt::Result {
+        if options.century {
+            write!(w, "{:04}", self.date.year())?;
         } else {
-            day
-        };
+            write!(w, "{:02}", self.date.year() % 100)?;
+        }
 
-        let long = format!(
-            "{}{:02}{:02}-{:03}{}",
-            self.date.year(),
+        write!(
+            w,
+            "{:02}{:02}",
             self.date.month(),
-            day_or_coordination,
-            self.serial,
-            self.control
-        );
+            self.day_or_coordination()
+        )?;
+
+        if options.separator {
+            if options.century {
+                w.write_char('-')?;
+            } else {
+                w.write_char(Separator::for_age(self.age_at(date)).as_char())?;
+            }
+        }
 
-        let short = String::from(&long[2..]);
+        write!(w, "{:03}{}", self.serial, self.control)
+    }
 
-        FormattedPersonnummer { long, short }
+    /// Returns the day as written in the personal identity number, meaning the day with the
+    /// coordination number added for coordination numbers.
+    fn day_or_coordination(&self) -> u32 {
+        if self.coordination {
+            self.date.day() + COORDINATION_NUMBER
+        } else {
+            self.date.day()
+        }
     }
 
     /// Validate a [Personnummer]. The validation requires a valid date and that the Luhn checksum
-    /// matches the control digit.
+    /// matches the control digit. For coordination numbers the checksum is calculated on the day
+    /// with the coordination number added, the same way it's written.
     pub fn valid(&self) -> bool {
-        let ymd = format!(
-            "{:02}{:02}{:02}",
-            self.date.year() % 100,
-            self.date.month(),
-            self.date.day()
-        );
+        self.serial > 0 && self.checksum() == self.control
+    }
 
-        let to_control = format!("{:06}{:03}", ymd, self.serial);
+    /// Returns the control digit calculated from the date and serial with the Luhn algorithm. It's
+    /// the same as [Personnummer::control()] if the checksum is valid.
+    pub fn checksum(&self) -> u8 {
+        // The nine digits of the ten digit format without the control digit, YYMMDDNNN.
+        let value = (self.date.year() % 100) as u32 * 10_000_000
+            + self.date.month() * 100_000
+            + self.day_or_coordination() * 1_000
+            + self.serial;
 
-        self.serial > 0 && luhn(to_control) == self.control
+        let checksum = (0..9)
+            .map(|idx| luhn_value(idx, value / 10u32.pow(8 - idx as u32) % 10))
+            .sum();
+
+        luhn_control(checksum)
     }
 
     /// Return the age of the person holding the personal identity number. The dates used for the
-    /// person and the current date are naive dates.
+    /// person and the current date are naive dates. The current date is in UTC unless the
+    /// `chrono-tz` feature is enabled which uses the current date in Sweden.
+    #[cfg(feature = "clock")]
     pub fn get_age(&self) -> i32 {
-        let now = Utc::now();
+        self.age_at(today()) as i32
+    }
 
-        if self.date.month() > now.month()
-            || self.date.month() == now.month() && self.date.day() > now.day()
-        {
-            now.year() - self.date.year() - 1
+    /// Return the age of the person holding the personal identity number at the given date. The
+    /// age is 0 for dates before the birth date. People born on a leap day turn a year older on
+    /// the 1st of March in non leap years, see [Personnummer::age_at_with_policy()] to change this.
+    pub fn age_at(&self, date: impl Into<Date>) -> u32 {
+        self.age_at_with_policy(date, LeapDayPolicy::default())
+    }
+
+    /// Returns the [Gender] of the person holding the personal identity number.
+    pub fn gender(&self) -> Gender {
+        if (self.serial % 10).is_multiple_of(2) {
+            Gender::Female
         } else {
-            now.year() - self.date.year()
+            Gender::Male
         }
     }
 
     /// Check if the person holding the personal identity number is a female.
     pub fn is_female(&self) -> bool {
-        (self.serial % 10) % 2 == 0
+        self.gender() == Gender::Female
     }
 
     /// Check if the person holding the personal identity number is a male.
     pub fn is_male(&self) -> bool {
-        !self.is_female()
+        self.gender() == Gender::Male
     }
 
     /// Check if the personal identity number is a coordination number.
@@ -206,6 +797,26 @@ impl Personnummer {
         self.coordination
     }
 
+    /// Birth date of the person holding the personal identity number. For coordination numbers
+    /// this is the date with the coordination number removed from the day.
+    pub fn birth_date(&self) -> Date {
+        self.date
+    }
+
+    /// Day of the week of the birth date.
+    #[cfg(feature = "chrono")]
+    pub fn weekday_of_birth(&self) -> chrono::Weekday {
+        use chrono::Datelike;
+
+        chrono::NaiveDate::from(self.date).weekday()
+    }
+
+    /// Check if the person holding the personal identity number was born on the 29th of
+    /// February.
+    pub fn is_born_on_leap_day(&self) -> bool {
+        self.date.month() == 2 && self.date.day() == 29
+    }
+
     /// Year of birth date.
     pub fn year(&self) -> i32 {
         self.date.year()
@@ -225,29 +836,199 @@ impl Personnummer {
     pub fn serial(&self) -> u32 {
         self.serial
     }
+
+    /// Control digit of personal identity number, the last digit.
+    pub fn control(&self) -> u8 {
+        self.control
+    }
+
+    /// Century of birth date, e.g. `19` for `1990`.
+    pub fn century(&self) -> i32 {
+        self.date.year().div_euclid(100)
+    }
+
+    /// Year of birth date without century, e.g. `90` for `1990`.
+    pub fn two_digit_year(&self) -> u32 {
+        self.date.year().rem_euclid(100) as u32
+    }
+
+    /// Returns all [Parts] of the personal identity number.
+    pub fn into_parts(self) -> Parts {
+        Parts {
+            year: self.date.year(),
+            month: self.date.month(),
+            day: self.date.day(),
+            serial: self.serial,
+            control: self.control,
+            coordination: self.coordination,
+        }
+    }
+}
+
+/// Creates a [Personnummer] from [Parts] stored separately. The date is validated the same way as
+/// when parsing but not the checksum, use [Personnummer::valid()] to check it.
+impl TryFrom<Parts> for Personnummer {
+    type Error = PersonnummerError;
+
+    fn try_from(parts: Parts) -> Result<Self, PersonnummerError> {
+        if !(0..=MAX_YEAR).contains(&parts.year)
+            || parts.serial > 999
+            || parts.control > 9
+            || parts.day > 31
+        {
+            return Err(PersonnummerError::InvalidInput);
+        }
+
+        let day = if parts.coordination {
+            parts.day + COORDINATION_NUMBER
+        } else {
+            parts.day
+        };
+
+        Personnummer::from_parts(
+            parts.year,
+            parts.month,
+            day,
+            parts.serial,
+            parts.control,
+            '\0',
+        )
+    }
+}
+
+/// Returns the number represented by a slice of ASCII digits.
+fn digits_to_u32(digits: &[u8]) -> u32 {
+    digits
+        .iter()
+        .fold(0, |acc, d| acc * 10 + u32::from(d - b'0'))
+}
+
+/// Returns the most specific [PersonnummerError] for input that didn't match the expected format.
+fn diagnose(pnr: &str) -> PersonnummerError {
+    let mut digits = 0;
+    let mut separator = None;
+
+    for (offset, c) in pnr.char_indices() {
+        if c.is_ascii_digit() {
+            digits += 1;
+            continue;
+        }
+
+        let at_separator = separator.is_none() && (digits == 6 || digits == 8);
+
+        if !at_separator {
+            return match c {
+                '-' | '+' => PersonnummerError::InvalidSeparator { offset, found: c },
+                _ => PersonnummerError::NonDigit { offset, found: c },
+            };
+        }
+
+        if c != '-' && c != '+' {
+            // Anything but a digit directly after the date is most likely a separator.
+            let followed_by_digit = pnr[offset + c.len_utf8()..]
+                .chars()
+                .next()
+                .is_some_and(|n| n.is_ascii_digit());
+
+            return if followed_by_digit {
+                PersonnummerError::InvalidSeparator { offset, found: c }
+            } else {
+                PersonnummerError::NonDigit { offset, found: c }
+            };
+        }
+
+        separator = Some((offset, c, digits));
+    }
+
+    if let Some((offset, found, date_digits)) = separator {
+        match digits - date_digits {
+            4 => {}
+            3 => return PersonnummerError::MissingControlDigit,
+            _ => return PersonnummerError::InvalidSeparator { offset, found },
+        }
+    }
+
+    match digits {
+        9 | 11 => PersonnummerError::MissingControlDigit,
+        10 | 12 => PersonnummerError::InvalidInput,
+        _ => PersonnummerError::WrongLength(digits),
+    }
+}
+
+/// Returns the current date used when nothing else is specified. With the `chrono-tz` feature this
+/// is the current date in Sweden, otherwise it's the current date in UTC. Methods taking a date,
+/// e.g. [Personnummer::age_at()], can be used to supply any other date.
+#[cfg(all(feature = "clock", not(feature = "chrono-tz")))]
+fn today() -> Date {
+    Utc::now().date_naive().into()
 }
 
-/// Calculate the checksum based on luhn algorithm. See more information here:
-/// https://en.wikipedia.org/wiki/Luhn_algorithm.
-fn luhn(value: String) -> u8 {
-    let checksum = value
-        .chars()
-        .map(|c| c.to_digit(10).unwrap_or(0))
-        .enumerate()
-        .fold(0, |acc, (idx, v)| {
-            let value = if idx % 2 == 0 { v * 2 } else { v };
-            acc + if value > 9 { value - 9 } else { value }
+#[cfg(feature = "chrono-tz")]
+fn today() -> Date {
+    Utc::now()
+        .with_timezone(&chrono_tz::Europe::Stockholm)
+        .date_naive()
+        .into()
+}
+
+/// Parse and validate a personal identity number in a single pass over the input without any
+/// allocations. This is the same as [Personnummer::parse()] but faster for high throughput
+/// validation.
+#[cfg(feature = "clock")]
+pub fn parse_valid(pnr: &str) -> Result<Personnummer, PersonnummerError> {
+    let (pnr, expected) = Personnummer::parse_at(pnr, today)?;
+
+    if pnr.serial == 0 {
+        return Err(PersonnummerError::InvalidSerial);
+    }
+
+    if expected != pnr.control {
+        return Err(PersonnummerError::InvalidChecksum {
+            expected,
+            found: pnr.control,
         });
+    }
+
+    Ok(pnr)
+}
+
+/// Check if the input is a valid personal identity number, see [parse_valid()].
+#[cfg(feature = "clock")]
+pub fn quick_valid(pnr: &str) -> bool {
+    parse_valid(pnr).is_ok()
+}
 
-    (10 - (checksum as u8 % 10)) % 10
+/// Returns the value a digit at the index adds to the checksum based on luhn algorithm. See more
+/// information here: https://en.wikipedia.org/wiki/Luhn_algorithm.
+fn luhn_value(idx: usize, digit: u32) -> u32 {
+    let value = if idx.is_multiple_of(2) {
+        digit * 2
+    } else {
+        digit
+    };
+
+    if value > 9 {
+        value - 9
+    } else {
+        value
+    }
+}
+
+/// Returns the control digit for a Luhn sum.
+fn luhn_control(checksum: u32) -> u8 {
+    (10 - (checksum % 10) as u8) % 10
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Duration;
+    use alloc::{vec, vec::Vec};
+    #[cfg(feature = "clock")]
+    use chrono::{Datelike, Duration, NaiveDate, Weekday};
+    #[cfg(feature = "clock")]
     use std::collections::HashMap;
 
+    #[cfg(feature = "clock")]
     #[test]
     fn test_invalid_date() {
         let cases = vec!["19901301-1111", "2017-02-29", "", "not-a-date"];
@@ -257,6 +1038,131 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_parse_errors() {
+        let cases = vec![
+            ("", PersonnummerError::WrongLength(0)),
+            ("1990", PersonnummerError::WrongLength(4)),
+            (
+                "19900101-00170",
+                PersonnummerError::InvalidSeparator {
+                    offset: 8,
+                    found: '-',
+                },
+            ),
+            ("199001010017123", PersonnummerError::WrongLength(15)),
+            ("19900101-001", PersonnummerError::MissingControlDigit),
+            ("900101001", PersonnummerError::MissingControlDigit),
+            (
+                "900101/0017",
+                PersonnummerError::InvalidSeparator {
+                    offset: 6,
+                    found: '/',
+                },
+            ),
+            (
+                "9001-010017",
+                PersonnummerError::InvalidSeparator {
+                    offset: 4,
+                    found: '-',
+                },
+            ),
+            (
+                "900101--0017",
+                PersonnummerError::InvalidSeparator {
+                    offset: 7,
+                    found: '-',
+                },
+            ),
+            (
+                "9001O1-0017",
+                PersonnummerError::NonDigit {
+                    offset: 4,
+                    found: 'O',
+                },
+            ),
+            (
+                "900101-0017 ",
+                PersonnummerError::NonDigit {
+                    offset: 11,
+                    found: ' ',
+                },
+            ),
+            ("19901301-1111", PersonnummerError::InvalidMonth(13)),
+            ("19900001-1111", PersonnummerError::InvalidMonth(0)),
+            ("20170229-1111", PersonnummerError::InvalidDay(29)),
+            ("19900100-1111", PersonnummerError::InvalidDay(0)),
+            ("19900160-1111", PersonnummerError::InvalidDay(60)),
+            (
+                "19900192-1111",
+                PersonnummerError::InvalidCoordinationDay(92),
+            ),
+            (
+                "19900199-1111",
+                PersonnummerError::InvalidCoordinationDay(99),
+            ),
+            (
+                "19900291-1111",
+                PersonnummerError::InvalidCoordinationDay(91),
+            ),
+        ];
+
+        for (pnr, error) in cases {
+            assert_eq!(Personnummer::new(pnr).unwrap_err(), error, "{}", pnr);
+        }
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_parse_strict() {
+        assert!(Personnummer::parse("19900101-0017").is_ok());
+        assert_eq!(
+            Personnummer::parse("19900101-0018").unwrap_err(),
+            PersonnummerError::InvalidChecksum {
+                expected: 7,
+                found: 8
+            }
+        );
+        assert_eq!(
+            Personnummer::parse("19900101-0000").unwrap_err(),
+            PersonnummerError::InvalidSerial
+        );
+        assert!(Personnummer::new("19900101-0018").is_ok());
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_parse_valid() {
+        let cases = vec![
+            "19900101-0017",
+            "196408233234",
+            "000101-0107",
+            "510818-9167",
+            "19130401+2931",
+            "800161-3291",
+            "19900101-0018",
+            "19900101-0000",
+            "19901301-0017",
+            "",
+            "not-a-pnr",
+        ];
+
+        for tc in cases {
+            assert_eq!(
+                parse_valid(tc).map(|p| p.format().long()),
+                Personnummer::parse(tc).map(|p| p.format().long()),
+                "{}",
+                tc
+            );
+            assert_eq!(
+                quick_valid(tc),
+                Personnummer::new(tc).map(|p| p.valid()).unwrap_or(false)
+            );
+        }
+    }
+
+    #[cfg(feature = "clock")]
     #[test]
     fn test_valid_date_invalid_digits() {
         let cases = vec![
@@ -271,6 +1177,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "clock")]
     #[test]
     fn test_valid_personal_identity_number() {
         let cases = vec![
@@ -286,9 +1193,10 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "clock")]
     #[test]
     fn test_age() {
-        let now = Utc::now();
+        let now = NaiveDate::from(today());
 
         let days_in_a_year = 365;
         let leap_years_in_20_years = 20 / 4;
@@ -297,7 +1205,7 @@ mod tests {
         let leap_years_in_100_years = 100 / 4;
         let hundred_years_ago = (days_in_a_year * 100) + leap_years_in_100_years;
 
-        let twenty_tomorrow_date = (now - Duration::days(twenty_years_ago - 1)).date();
+        let twenty_tomorrow_date = now - Duration::days(twenty_years_ago - 1);
         let twenty_tomorrow = format!(
             "{}{:02}{:02}-1111",
             twenty_tomorrow_date.year(),
@@ -305,7 +1213,7 @@ mod tests {
             twenty_tomorrow_date.day()
         );
 
-        let twenty_yesterday_date = (now - Duration::days(twenty_years_ago + 1)).date();
+        let twenty_yesterday_date = now - Duration::days(twenty_years_ago + 1);
         let twenty_yesterday = format!(
             "{}{:02}{:02}-1111",
             twenty_yesterday_date.year(),
@@ -313,7 +1221,7 @@ mod tests {
             twenty_yesterday_date.day()
         );
 
-        let hundred_years_ago_date = (now - Duration::days(hundred_years_ago)).date();
+        let hundred_years_ago_date = now - Duration::days(hundred_years_ago);
         let hundred_years_age = format!(
             "{}{:02}{:02}-1111",
             hundred_years_ago_date.year(),
@@ -332,6 +1240,25 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_age_at() {
+        let p = Personnummer::new("19900101-0017").unwrap();
+
+        let cases = vec![
+            (Date::from_ymd(1980, 1, 1).unwrap(), 0),
+            (Date::from_ymd(1990, 1, 1).unwrap(), 0),
+            (Date::from_ymd(2007, 12, 31).unwrap(), 17),
+            (Date::from_ymd(2008, 1, 1).unwrap(), 18),
+            (Date::from_ymd(2090, 1, 1).unwrap(), 100),
+        ];
+
+        for (date, age) in cases {
+            assert_eq!(p.age_at(date), age);
+        }
+    }
+
+    #[cfg(feature = "clock")]
     #[test]
     fn test_gender() {
         let mut cases: HashMap<&str, bool> = HashMap::new();
@@ -348,14 +1275,240 @@ mod tests {
             assert!(p.valid());
             assert_eq!(p.is_female(), is_female);
             assert_eq!(p.is_male(), !is_female);
+            assert_eq!(
+                p.gender(),
+                if is_female {
+                    Gender::Female
+                } else {
+                    Gender::Male
+                }
+            );
+        }
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_format_into() {
+        let cases = vec![
+            ("19900101-0017", "19900101-0017", "900101-0017"),
+            ("196408233234", "19640823-3234", "640823-3234"),
+            ("800161-3294", "19800161-3294", "800161-3294"),
+            ("00000101-0107", "00000101-0107", "000101+0107"),
+            ("09990101-0109", "09990101-0109", "990101+0109"),
+        ];
+
+        for (pnr, long, short) in cases {
+            let p = Personnummer::new(pnr).unwrap();
+
+            let mut long_buf = String::new();
+            let mut short_buf = String::new();
+            p.format_long_into(&mut long_buf).unwrap();
+            p.format_short_into(&mut short_buf).unwrap();
+
+            assert_eq!(long_buf, long);
+            assert_eq!(short_buf, short);
+            assert_eq!(p.to_string(), long);
+            assert_eq!(p.format().long(), long);
+            assert_eq!(p.format().short(), short);
+        }
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_format_at() {
+        let p = Personnummer::new("19130401+2931").unwrap();
+
+        let cases = vec![
+            (Date::from_ymd(2013, 3, 31).unwrap(), "130401-2931"),
+            (Date::from_ymd(2013, 4, 1).unwrap(), "130401+2931"),
+            (Date::from_ymd(1950, 1, 1).unwrap(), "130401-2931"),
+        ];
+
+        for (date, short) in cases {
+            let formatted = p.format_at(date);
+
+            assert_eq!(formatted.long(), "19130401-2931");
+            assert_eq!(formatted.short(), short);
+        }
+
+        assert_eq!(p.format().short(), "130401+2931");
+        assert_eq!(
+            p.format_with(FormatOptions::ten_digits().with_separator()),
+            "130401+2931"
+        );
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_masked() {
+        let cases = vec![
+            ("19900101-0017", "19900101-XXXX"),
+            ("800161-3294", "19800161-XXXX"),
+            ("00000101-0107", "00000101-XXXX"),
+        ];
+
+        for (pnr, masked) in cases {
+            let p = Personnummer::new(pnr).unwrap();
+
+            assert_eq!(p.masked().to_string(), masked);
+            assert_eq!(format!("{:?}", p), format!("Personnummer({})", masked));
+        }
+    }
+
+    #[cfg(all(feature = "zeroize", feature = "clock"))]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut p = Personnummer::new("19900101-0017").unwrap();
+        p.zeroize();
+
+        assert_eq!(p.format().long(), "19700101-0000");
+
+        let mut formatted = Personnummer::new("19900101-0017").unwrap().format();
+        formatted.zeroize();
+
+        assert_eq!(formatted.long(), "");
+        assert_eq!(formatted.short(), "");
+    }
+
+    #[cfg(all(feature = "subtle", feature = "clock"))]
+    #[test]
+    fn test_ct_eq() {
+        use subtle::ConstantTimeEq;
+
+        let p = Personnummer::new("19900101-0017").unwrap();
+
+        assert!(bool::from(
+            p.ct_eq(&Personnummer::new("9001010017").unwrap())
+        ));
+        assert!(!bool::from(
+            p.ct_eq(&Personnummer::new("19900101-0025").unwrap())
+        ));
+        assert!(!bool::from(
+            p.ct_eq(&Personnummer::new("18900101-0017").unwrap())
+        ));
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_format_with() {
+        let p = Personnummer::new("900101-0017").unwrap();
+
+        let cases = vec![
+            (FormatOptions::ten_digits(), "9001010017"),
+            (FormatOptions::ten_digits().with_separator(), "900101-0017"),
+            (FormatOptions::twelve_digits(), "199001010017"),
+            (
+                FormatOptions::twelve_digits().with_separator(),
+                "19900101-0017",
+            ),
+            (
+                FormatOptions::twelve_digits()
+                    .with_separator()
+                    .without_separator(),
+                "199001010017",
+            ),
+        ];
+
+        for (options, expected) in cases {
+            assert_eq!(p.format_with(options), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_at_resolves_date_lazily() {
+        let date = Date::from_ymd(2020, 1, 1).unwrap();
+
+        assert!(Personnummer::parse_at("19900101-0017", || unreachable!()).is_ok());
+        assert!(Personnummer::parse_at("199001010017", || unreachable!()).is_ok());
+        assert_eq!(
+            Personnummer::parse_at("900101-0017", || date).map(|(p, _)| p.year()),
+            Ok(1990)
+        );
+    }
+
+    #[test]
+    fn test_format_with_at() {
+        let p = Personnummer::from_u64(191304012931).unwrap();
+        let options = FormatOptions::ten_digits().with_separator();
+
+        let cases = vec![
+            (Date::from_ymd(2013, 3, 31).unwrap(), "130401-2931"),
+            (Date::from_ymd(2013, 4, 1).unwrap(), "130401+2931"),
+        ];
+
+        for (date, expected) in cases {
+            let mut buf = String::new();
+            p.format_with_into_at(options, date, &mut buf).unwrap();
+
+            assert_eq!(p.format_with_at(options, date), expected);
+            assert_eq!(buf, expected);
+        }
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_u64_round_trip() {
+        let cases = vec![
+            ("19900101-0017", 199001010017),
+            ("800161-3294", 198001613294),
+            ("19130401+2931", 191304012931),
+            ("19900101-1111", 199001011111),
+            ("00000101-0107", 1010107),
+            ("09990101-0109", 99901010109),
+        ];
+
+        for (pnr, packed) in cases {
+            let p = Personnummer::new(pnr).unwrap();
+
+            assert_eq!(p.to_u64(), packed);
+            assert_eq!(
+                Personnummer::from_u64(packed).unwrap().format().long(),
+                p.format().long()
+            );
+            assert_eq!(
+                Personnummer::new(&p.format().long()).unwrap().to_u64(),
+                packed
+            );
         }
+
+        assert!(Personnummer::from_u64(199013010017).is_err());
+        assert!(Personnummer::from_u64(1_000_000_000_000).is_err());
     }
 
+    #[test]
+    fn test_checksum() {
+        let cases = vec![
+            (199001010010, 7),
+            (199001010017, 7),
+            (198001613290, 1),
+            (191304012930, 1),
+        ];
+
+        for (packed, checksum) in cases {
+            assert_eq!(Personnummer::from_u64(packed).unwrap().checksum(), checksum);
+        }
+    }
+
+    #[test]
+    fn test_coordination_checksum() {
+        // The control digit of a coordination number is calculated on the number as written, with
+        // 60 added to the day, so 800161-3291 is valid and 800161-3294 isn't.
+        let valid = Personnummer::from_u64(198001613291).unwrap();
+        let real_day = Personnummer::from_u64(198001613294).unwrap();
+
+        assert!(valid.valid());
+        assert!(!real_day.valid());
+        assert_eq!(real_day.checksum(), 1);
+    }
+
+    #[cfg(feature = "clock")]
     #[test]
     fn test_coordination() {
         let mut cases: HashMap<&str, bool> = HashMap::new();
 
-        cases.insert("800161-3294", true);
+        cases.insert("800161-3291", true);
         cases.insert("800101-3294", false);
         cases.insert("640327-3813", false);
 
@@ -366,4 +1519,265 @@ mod tests {
             assert_eq!(p.is_coordination_number(), is_coordination);
         }
     }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_weekday_and_leap_day() {
+        let cases = vec![
+            ("19900101-0017", Weekday::Mon, false),
+            ("20000229-0017", Weekday::Tue, true),
+            ("800161-3294", Weekday::Tue, false),
+        ];
+
+        for (pnr, weekday, leap_day) in cases {
+            let p = Personnummer::new(pnr).unwrap();
+
+            assert_eq!(p.weekday_of_birth(), weekday);
+            assert_eq!(p.is_born_on_leap_day(), leap_day);
+        }
+    }
+
+    #[test]
+    fn test_new_at() {
+        let cases = vec![
+            ("000101-0107", (2025, 1, 1), 2000),
+            ("000101-0107", (1999, 12, 31), 1900),
+            ("000101+0107", (2025, 1, 1), 1900),
+            ("250101-0107", (2025, 1, 1), 2025),
+            ("260101-0107", (2025, 1, 1), 1926),
+            ("19900101-0017", (1950, 1, 1), 1990),
+            ("20000101+0107", (2025, 1, 1), 2000),
+        ];
+
+        for (pnr, (y, m, d), year) in cases {
+            let date = Date::from_ymd(y, m, d).unwrap();
+
+            assert_eq!(Personnummer::new_at(pnr, date).unwrap().year(), year);
+        }
+
+        for (y, m, d) in [(12_000, 1, 1), (50, 1, 1)] {
+            let date = Date::from_ymd(y, m, d).unwrap();
+
+            assert_eq!(
+                Personnummer::new_at("000101+0107", date).err(),
+                Some(PersonnummerError::InvalidInput)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_ambiguous() {
+        let date = Date::from_ymd(2024, 6, 1).unwrap();
+        let cases = vec![
+            ("240101-0018", vec![2024, 1924]),
+            ("2401010018", vec![2024, 1924]),
+            ("240101+0018", vec![1924, 2024]),
+            ("241201-0015", vec![1924]),
+            ("000229-0013", vec![2000]),
+            ("000229+0013", vec![2000]),
+            ("19240101-0018", vec![1924]),
+        ];
+
+        for (pnr, years) in cases {
+            let candidates = Personnummer::parse_ambiguous_at(pnr, date).unwrap();
+
+            assert_eq!(
+                candidates.iter().map(|p| p.year()).collect::<Vec<_>>(),
+                years,
+                "{}",
+                pnr
+            );
+        }
+
+        assert_eq!(
+            Personnummer::parse_ambiguous_at("240101-0017", date).err(),
+            Some(PersonnummerError::InvalidChecksum {
+                expected: 8,
+                found: 7
+            })
+        );
+        assert_eq!(
+            Personnummer::parse_ambiguous_at("240230-0017", date).err(),
+            Some(PersonnummerError::InvalidDay(30))
+        );
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_birth_date() {
+        let cases = vec![
+            ("19900101-0017", Date::from_ymd(1990, 1, 1).unwrap()),
+            ("800161-3294", Date::from_ymd(1980, 1, 1).unwrap()),
+            ("19130401+2931", Date::from_ymd(1913, 4, 1).unwrap()),
+        ];
+
+        for (pnr, date) in cases {
+            assert_eq!(Personnummer::new(pnr).unwrap().birth_date(), date);
+        }
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_parts() {
+        let pnr = Personnummer::new("800161-3291").unwrap();
+
+        assert_eq!(pnr.century(), 19);
+        assert_eq!(pnr.two_digit_year(), 80);
+        assert_eq!(pnr.control(), 1);
+
+        let parts = pnr.into_parts();
+
+        assert_eq!(
+            parts,
+            Parts {
+                year: 1980,
+                month: 1,
+                day: 1,
+                serial: 329,
+                control: 1,
+                coordination: true,
+            }
+        );
+
+        let pnr = Personnummer::try_from(parts).unwrap();
+
+        assert!(pnr.valid());
+        assert_eq!(pnr.to_string(), "19800161-3291");
+
+        let cases = vec![
+            (
+                Parts {
+                    day: 30,
+                    month: 2,
+                    ..parts
+                },
+                PersonnummerError::InvalidCoordinationDay(90),
+            ),
+            (
+                Parts { month: 13, ..parts },
+                PersonnummerError::InvalidMonth(13),
+            ),
+            (
+                Parts {
+                    serial: 1_000,
+                    ..parts
+                },
+                PersonnummerError::InvalidInput,
+            ),
+            (
+                Parts {
+                    control: 10,
+                    ..parts
+                },
+                PersonnummerError::InvalidInput,
+            ),
+            (
+                Parts {
+                    year: 10_000,
+                    ..parts
+                },
+                PersonnummerError::InvalidInput,
+            ),
+            (Parts { year: -1, ..parts }, PersonnummerError::InvalidInput),
+        ];
+
+        for (parts, error) in cases {
+            assert_eq!(Personnummer::try_from(parts).unwrap_err(), error);
+        }
+
+        let mut set = PersonnummerSet::new();
+
+        for year in [-1, 0, 9999, 10_000] {
+            if let Ok(pnr) = Personnummer::try_from(Parts { year, ..parts }) {
+                set.insert(&pnr);
+            }
+        }
+
+        assert_eq!(
+            set.iter().map(|p| p.year()).collect::<Vec<_>>(),
+            vec![0, 9999]
+        );
+    }
+
+    #[cfg(all(feature = "macros", feature = "clock"))]
+    #[test]
+    fn test_pnr_macro() {
+        let cases = vec![
+            (pnr!("19900101-0017"), "19900101-0017"),
+            (pnr!("199001010017"), "19900101-0017"),
+            (pnr!("19800161-3291"), "19800161-3291"),
+            (pnr!("19130401+2931"), "19130401-2931"),
+        ];
+
+        for (p, long) in cases {
+            assert!(p.valid());
+            assert_eq!(p.to_u64(), Personnummer::parse(long).unwrap().to_u64());
+            assert_eq!(p.to_string(), long);
+        }
+    }
+
+    /// Exercise every public parsing entry point with the input. Parsing is used on untrusted input
+    /// so nothing may panic, and the strict parsers must agree with each other.
+    #[cfg(feature = "clock")]
+    fn parse_all(input: &str) {
+        let parsed = Personnummer::new(input);
+        let strict = Personnummer::parse(input);
+
+        assert_eq!(strict.is_ok(), parse_valid(input).is_ok());
+        assert_eq!(strict.is_ok(), quick_valid(input));
+        assert_eq!(strict.is_ok(), parsed.as_ref().is_ok_and(|p| p.valid()));
+
+        if let Err(e) = strict {
+            let _ = e.message(Locale::Sv);
+        }
+
+        if let Ok(p) = parsed {
+            let _ = p.suggest_corrections();
+            let _ = p.validate();
+            let _ = p.format();
+        }
+
+        if let Ok(fixed) = input.as_bytes().try_into() {
+            let _ = bulk::is_valid(fixed);
+        }
+
+        let _ = scan::find_all(input).count();
+        let _ = scan::redact(input, &scan::RedactStyle::KeepBirthYear);
+    }
+
+    proptest::proptest! {
+        #[cfg(feature = "clock")]
+        #[test]
+        fn test_parse_any_input(input in "\\PC*") {
+            parse_all(&input);
+        }
+
+        #[cfg(feature = "clock")]
+        #[test]
+        fn test_parse_number_like_input(input in "[0-9]{0,9}[-+ /a]?[0-9]{0,5}[a+]?") {
+            parse_all(&input);
+        }
+
+        #[test]
+        fn test_new_at_any_date(
+            input in "[0-9]{6}[-+]?[0-9]{4}",
+            year in proptest::prelude::any::<i32>(),
+            month in 1u32..=12,
+            day in 1u32..=28,
+        ) {
+            let date = Date::from_ymd(year, month, day).unwrap();
+
+            if let Ok(p) = Personnummer::new_at(&input, date) {
+                assert!(p.year() <= year);
+                assert_eq!(Personnummer::from_u64(p.to_u64()).unwrap().year(), p.year());
+            }
+        }
+
+        #[test]
+        fn test_from_u64_any_value(value: u64) {
+            if let Ok(p) = Personnummer::from_u64(value) {
+                assert_eq!(p.to_u64(), value);
+            }
+        }
+    }
 }
@@ -0,0 +1,90 @@
+use crate::PersonnummerError;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+/// [Locale] selects the language of messages from [PersonnummerError::message()].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// English, the same messages as the [core::fmt::Display] implementation.
+    #[default]
+    En,
+    /// Swedish.
+    Sv,
+}
+
+impl PersonnummerError {
+    /// Returns the error message in the given [Locale], suitable to show to end users.
+    pub fn message(&self, locale: Locale) -> String {
+        match locale {
+            Locale::En => self.to_string(),
+            Locale::Sv => self.message_sv(),
+        }
+    }
+
+    fn message_sv(&self) -> String {
+        match self {
+            PersonnummerError::InvalidInput => "Ogiltigt format".to_string(),
+            PersonnummerError::WrongLength(digits) => format!(
+                "Ogiltig längd, förväntade 10 eller 12 siffror men fick {}",
+                digits
+            ),
+            PersonnummerError::InvalidSeparator { offset, found } => {
+                format!("Ogiltigt skiljetecken '{}' på position {}", found, offset)
+            }
+            PersonnummerError::NonDigit { offset, found } => {
+                format!("Ogiltigt tecken '{}' på position {}", found, offset)
+            }
+            PersonnummerError::InvalidMonth(month) => format!("Ogiltig månad {}", month),
+            PersonnummerError::InvalidDay(day) => format!("Ogiltig dag {}", day),
+            PersonnummerError::MissingControlDigit => "Kontrollsiffra saknas".to_string(),
+            PersonnummerError::InvalidSerial => "Ogiltigt födelsenummer".to_string(),
+            PersonnummerError::InvalidChecksum { expected, found } => format!(
+                "Ogiltig kontrollsiffra, förväntade {} men fick {}",
+                expected, found
+            ),
+            PersonnummerError::FutureBirthDate => "Födelsedatumet är i framtiden".to_string(),
+            PersonnummerError::InvalidCoordinationDay(day) => {
+                format!("Ogiltig samordningsdag {}", day)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_message() {
+        let cases = vec![
+            (
+                PersonnummerError::InvalidMonth(13),
+                "Invalid month 13",
+                "Ogiltig månad 13",
+            ),
+            (
+                PersonnummerError::MissingControlDigit,
+                "Missing control digit",
+                "Kontrollsiffra saknas",
+            ),
+            (
+                PersonnummerError::InvalidChecksum {
+                    expected: 7,
+                    found: 8,
+                },
+                "Invalid checksum, expected control digit 7 but got 8",
+                "Ogiltig kontrollsiffra, förväntade 7 men fick 8",
+            ),
+        ];
+
+        for (error, en, sv) in cases {
+            assert_eq!(error.message(Locale::En), en);
+            assert_eq!(error.message(Locale::Sv), sv);
+            assert_eq!(error.to_string(), en);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{Gender, Personnummer};
+
+/// The width in years of an [AgeBand].
+const AGE_BAND_WIDTH: i32 = 10;
+
+/// [Profile] selects which parts of a [Personnummer] to keep in a [Minimal].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Keep only the year of birth.
+    Year,
+    /// Keep the year of birth and gender.
+    YearAndGender,
+    /// Keep only the age band.
+    AgeBand,
+    /// Keep the age band and gender.
+    AgeBandAndGender,
+    /// Keep only the gender.
+    Gender,
+}
+
+/// [AgeBand] is an age range of ten years, e.g. 30 to 39.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AgeBand {
+    lower: i32,
+}
+
+impl AgeBand {
+    /// Returns the [AgeBand] containing the age.
+    pub fn from_age(age: i32) -> Self {
+        AgeBand {
+            lower: age.div_euclid(AGE_BAND_WIDTH) * AGE_BAND_WIDTH,
+        }
+    }
+
+    /// The lowest age in the band.
+    pub fn lower(&self) -> i32 {
+        self.lower
+    }
+
+    /// The highest age in the band.
+    pub fn upper(&self) -> i32 {
+        self.lower + AGE_BAND_WIDTH - 1
+    }
+}
+
+/// [Minimal] holds the reduced data from a [Personnummer] selected by a [Profile]. It never
+/// contains the full birth date or the serial so it's safe to persist where data minimization is
+/// required. Create with [Personnummer::minimal()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Minimal {
+    year: Option<i32>,
+    age_band: Option<AgeBand>,
+    gender: Option<Gender>,
+}
+
+impl Minimal {
+    /// Year of birth if kept by the [Profile].
+    pub fn year(&self) -> Option<i32> {
+        self.year
+    }
+
+    /// Age band at the time of creation if kept by the [Profile].
+    pub fn age_band(&self) -> Option<AgeBand> {
+        self.age_band
+    }
+
+    /// Gender if kept by the [Profile].
+    pub fn gender(&self) -> Option<Gender> {
+        self.gender
+    }
+}
+
+impl Personnummer {
+    /// Returns a [Minimal] with only the parts of the [Personnummer] selected by the [Profile].
+    /// Age bands are calculated from the current age, see [Personnummer::get_age()].
+    #[cfg(feature = "clock")]
+    pub fn minimal(&self, profile: Profile) -> Minimal {
+        let (year, age_band, gender) = match profile {
+            Profile::Year => (true, false, false),
+            Profile::YearAndGender => (true, false, true),
+            Profile::AgeBand => (false, true, false),
+            Profile::AgeBandAndGender => (false, true, true),
+            Profile::Gender => (false, false, true),
+        };
+
+        Minimal {
+            year: year.then(|| self.year()),
+            age_band: age_band.then(|| AgeBand::from_age(self.get_age())),
+            gender: gender.then(|| self.gender()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "clock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimal() {
+        let p = Personnummer::new("19900101-0017").unwrap();
+
+        let m = p.minimal(Profile::YearAndGender);
+        assert_eq!(m.year(), Some(1990));
+        assert_eq!(m.gender(), Some(Gender::Male));
+        assert_eq!(m.age_band(), None);
+
+        let m = p.minimal(Profile::AgeBand);
+        assert_eq!(m.year(), None);
+        assert_eq!(m.gender(), None);
+        assert_eq!(m.age_band(), Some(AgeBand::from_age(p.get_age())));
+
+        let m = p.minimal(Profile::Gender);
+        assert_eq!(m.year(), None);
+        assert_eq!(m.age_band(), None);
+        assert_eq!(m.gender(), Some(Gender::Male));
+    }
+
+    #[test]
+    fn test_age_band() {
+        let cases = vec![
+            (0, 0, 9),
+            (9, 0, 9),
+            (10, 10, 19),
+            (37, 30, 39),
+            (100, 100, 109),
+        ];
+
+        for (age, lower, upper) in cases {
+            let band = AgeBand::from_age(age);
+
+            assert_eq!(band.lower(), lower);
+            assert_eq!(band.upper(), upper);
+        }
+    }
+}
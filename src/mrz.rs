@@ -0,0 +1,343 @@
+//! Read the machine readable zone (MRZ) of Swedish passports and national identity cards and
+//! cross-check it against a [Personnummer].
+//!
+//! Both the passport format with two lines of 44 characters (TD3) and the identity card format
+//! with three lines of 30 characters (TD1) are supported. The check digits of the document
+//! number, birth date, expiry date and the composite check digit are verified when parsing. The
+//! personal identity number is stored in the optional data as ten digits without separator.
+
+use crate::{Personnummer, PersonnummerError};
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// [Field] is a field of the machine readable zone protected by a check digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    DocumentNumber,
+    BirthDate,
+    ExpiryDate,
+    PersonalNumber,
+    Composite,
+}
+
+/// [MrzError] describes why a machine readable zone couldn't be read or doesn't match a
+/// [Personnummer].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MrzError {
+    /// The lines aren't two lines of 44 or three lines of 30 valid MRZ characters.
+    InvalidFormat,
+    /// The check digit of the field doesn't match.
+    InvalidCheckDigit(Field),
+    /// The document doesn't contain a personal identity number.
+    MissingPersonalNumber,
+    /// The personal identity number in the document isn't valid.
+    InvalidPersonalNumber(PersonnummerError),
+    /// The birth date in the document doesn't match the personal identity number.
+    BirthDateMismatch,
+    /// The personal identity number in the document doesn't match.
+    PersonalNumberMismatch,
+}
+
+impl fmt::Display for MrzError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MrzError::InvalidFormat => write!(f, "Invalid machine readable zone"),
+            MrzError::InvalidCheckDigit(field) => write!(f, "Invalid check digit for {:?}", field),
+            MrzError::MissingPersonalNumber => write!(f, "Missing personal identity number"),
+            MrzError::InvalidPersonalNumber(e) => {
+                write!(f, "Invalid personal identity number: {}", e)
+            }
+            MrzError::BirthDateMismatch => write!(f, "Birth date doesn't match"),
+            MrzError::PersonalNumberMismatch => write!(f, "Personal identity number doesn't match"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MrzError {}
+
+/// [Mrz] holds the fields of a machine readable zone with valid check digits, created with
+/// [parse()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mrz {
+    /// The document number without fillers.
+    pub document_number: String,
+    /// The three letter code of the nationality of the holder, e.g. `SWE`.
+    pub nationality: String,
+    /// The birth date as written, `YYMMDD`.
+    pub birth_date: String,
+    /// The expiry date as written, `YYMMDD`.
+    pub expiry_date: String,
+    /// The personal identity number as written, ten digits without separator.
+    pub personal_number: Option<String>,
+}
+
+/// Parse the lines of a machine readable zone and verify all check digits. Surrounding
+/// whitespace on each line is ignored.
+pub fn parse<S: AsRef<str>>(lines: &[S]) -> Result<Mrz, MrzError> {
+    let lines = lines
+        .iter()
+        .map(|l| l.as_ref().trim())
+        .collect::<alloc::vec::Vec<_>>();
+
+    if lines
+        .iter()
+        .flat_map(|l| l.bytes())
+        .any(|b| !matches!(b, b'0'..=b'9' | b'A'..=b'Z' | b'<'))
+    {
+        return Err(MrzError::InvalidFormat);
+    }
+
+    match lines.as_slice() {
+        [first, second] if first.len() == 44 && second.len() == 44 => parse_td3(second),
+        [first, second, third] if first.len() == 30 && second.len() == 30 && third.len() == 30 => {
+            parse_td1(first, second)
+        }
+        _ => Err(MrzError::InvalidFormat),
+    }
+}
+
+/// Parse the second line of a passport.
+fn parse_td3(line: &str) -> Result<Mrz, MrzError> {
+    let digit = |idx: usize| line.as_bytes()[idx];
+    let composite = [&line[0..10], &line[13..20], &line[21..43]].concat();
+
+    check(&line[0..9], digit(9), Field::DocumentNumber)?;
+    check(&line[13..19], digit(19), Field::BirthDate)?;
+    check(&line[21..27], digit(27), Field::ExpiryDate)?;
+    check(&line[28..42], digit(42), Field::PersonalNumber)?;
+    check(&composite, digit(43), Field::Composite)?;
+
+    Ok(Mrz {
+        document_number: field(&line[0..9]),
+        nationality: field(&line[10..13]),
+        birth_date: line[13..19].to_string(),
+        expiry_date: line[21..27].to_string(),
+        personal_number: personal_number(&line[28..42]),
+    })
+}
+
+/// Parse the first two lines of an identity card.
+fn parse_td1(first: &str, second: &str) -> Result<Mrz, MrzError> {
+    let digit = |idx: usize| second.as_bytes()[idx];
+    let composite = [
+        &first[5..30],
+        &second[0..7],
+        &second[8..15],
+        &second[18..29],
+    ]
+    .concat();
+
+    check(&first[5..14], first.as_bytes()[14], Field::DocumentNumber)?;
+    check(&second[0..6], digit(6), Field::BirthDate)?;
+    check(&second[8..14], digit(14), Field::ExpiryDate)?;
+    check(&composite, digit(29), Field::Composite)?;
+
+    Ok(Mrz {
+        document_number: field(&first[5..14]),
+        nationality: field(&second[15..18]),
+        birth_date: second[0..6].to_string(),
+        expiry_date: second[8..14].to_string(),
+        personal_number: personal_number(&first[15..30])
+            .or_else(|| personal_number(&second[18..29])),
+    })
+}
+
+impl Mrz {
+    /// Parse the personal identity number in the document. The century is resolved the same way
+    /// as for ten digits with [Personnummer::parse()].
+    #[cfg(feature = "clock")]
+    pub fn personnummer(&self) -> Result<Personnummer, MrzError> {
+        let personal_number = self
+            .personal_number
+            .as_ref()
+            .ok_or(MrzError::MissingPersonalNumber)?;
+
+        Personnummer::parse(personal_number).map_err(MrzError::InvalidPersonalNumber)
+    }
+
+    /// Check that the birth date and personal identity number in the document match the
+    /// [Personnummer]. The birth date is compared to the birth date of the [Personnummer], so
+    /// coordination numbers match the actual birth date.
+    pub fn cross_check(&self, pnr: &Personnummer) -> Result<(), MrzError> {
+        let birth = pnr.birth_date();
+        let birth_date = alloc::format!(
+            "{:02}{:02}{:02}",
+            birth.year().rem_euclid(100),
+            birth.month(),
+            birth.day()
+        );
+
+        if self.birth_date != birth_date {
+            return Err(MrzError::BirthDateMismatch);
+        }
+
+        let personal_number = self
+            .personal_number
+            .as_ref()
+            .ok_or(MrzError::MissingPersonalNumber)?;
+
+        if *personal_number != alloc::format!("{:010}", pnr.to_u64() % 10_000_000_000) {
+            return Err(MrzError::PersonalNumberMismatch);
+        }
+
+        Ok(())
+    }
+}
+
+/// Verify that the check digit of the data is the found digit.
+fn check(data: &str, found: u8, field: Field) -> Result<(), MrzError> {
+    // An empty optional field may have a filler instead of a check digit.
+    if found == b'<' && data.bytes().all(|b| b == b'<') {
+        return Ok(());
+    }
+
+    if check_digit(data.bytes()) != found {
+        return Err(MrzError::InvalidCheckDigit(field));
+    }
+
+    Ok(())
+}
+
+/// Returns the ICAO 9303 check digit as an ASCII digit, the sum of the character values weighted
+/// 7, 3, 1 repeating modulo 10.
+fn check_digit<I: Iterator<Item = u8>>(data: I) -> u8 {
+    let sum = data
+        .zip([7, 3, 1].iter().cycle())
+        .map(|(b, weight)| {
+            let value = match b {
+                b'0'..=b'9' => u32::from(b - b'0'),
+                b'A'..=b'Z' => u32::from(b - b'A') + 10,
+                _ => 0,
+            };
+
+            value * weight
+        })
+        .sum::<u32>();
+
+    b'0' + (sum % 10) as u8
+}
+
+/// Returns the field with trailing fillers removed.
+fn field(value: &str) -> String {
+    value.trim_end_matches('<').to_string()
+}
+
+/// Returns the personal identity number if the optional data starts with ten digits.
+fn personal_number(data: &str) -> Option<String> {
+    let data = data.trim_end_matches('<');
+
+    (data.len() == 10 && data.bytes().all(|b| b.is_ascii_digit())).then(|| data.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSPORT: [&str; 2] = [
+        "P<SWESVENSSON<<SVEN<<<<<<<<<<<<<<<<<<<<<<<<<",
+        "AA12345678SWE9001011M30010199001010017<<<<12",
+    ];
+
+    #[cfg(feature = "clock")]
+    const ID_CARD: [&str; 3] = [
+        "IDSWEAB123456719001010017<<<<<",
+        "9001011M3001019SWE<<<<<<<<<<<9",
+        "SVENSSON<<SVEN<<<<<<<<<<<<<<<<",
+    ];
+
+    #[test]
+    fn test_check_digit() {
+        // The examples from ICAO 9303 part 3.
+        assert_eq!(check_digit("520727".bytes()), b'3');
+        assert_eq!(check_digit("AB2134<<<".bytes()), b'5');
+        assert_eq!(check_digit("L898902C3".bytes()), b'6');
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_parse_passport() {
+        let mrz = parse(&PASSPORT).unwrap();
+
+        assert_eq!(mrz.document_number, "AA1234567");
+        assert_eq!(mrz.nationality, "SWE");
+        assert_eq!(mrz.birth_date, "900101");
+        assert_eq!(mrz.expiry_date, "300101");
+        assert_eq!(mrz.personal_number.as_deref(), Some("9001010017"));
+
+        let pnr = Personnummer::parse("19900101-0017").unwrap();
+
+        assert_eq!(mrz.personnummer().unwrap().to_u64(), pnr.to_u64());
+        assert_eq!(mrz.cross_check(&pnr), Ok(()));
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_parse_id_card() {
+        let mrz = parse(&ID_CARD).unwrap();
+
+        assert_eq!(mrz.document_number, "AB1234567");
+        assert_eq!(mrz.birth_date, "900101");
+        assert_eq!(mrz.personal_number.as_deref(), Some("9001010017"));
+        assert_eq!(
+            mrz.cross_check(&Personnummer::parse("19900101-0017").unwrap()),
+            Ok(())
+        );
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_cross_check() {
+        let mrz = parse(&PASSPORT).unwrap();
+
+        assert_eq!(
+            mrz.cross_check(&Personnummer::parse("19800101-3294").unwrap()),
+            Err(MrzError::BirthDateMismatch)
+        );
+        assert_eq!(
+            mrz.cross_check(&Personnummer::parse("19900101-0025").unwrap()),
+            Err(MrzError::PersonalNumberMismatch)
+        );
+    }
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_missing_personal_number() {
+        let mrz = parse(&[PASSPORT[0], "AA12345678SWE9001011M3001019<<<<<<<<<<<<<<<0"]).unwrap();
+        let pnr = Personnummer::parse("19900101-0017").unwrap();
+
+        assert_eq!(mrz.personal_number, None);
+        assert_eq!(
+            mrz.personnummer().err(),
+            Some(MrzError::MissingPersonalNumber)
+        );
+        assert_eq!(mrz.cross_check(&pnr), Err(MrzError::MissingPersonalNumber));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let with_line = |line: &str| parse(&[PASSPORT[0], line]);
+
+        assert_eq!(
+            with_line("AA12345679SWE9001011M30010199001010017<<<<12"),
+            Err(MrzError::InvalidCheckDigit(Field::DocumentNumber))
+        );
+        assert_eq!(
+            with_line("AA12345678SWE9001021M30010199001010017<<<<12"),
+            Err(MrzError::InvalidCheckDigit(Field::BirthDate))
+        );
+        assert_eq!(
+            with_line("AA12345678SWE9001011M30010199001010017<<<<13"),
+            Err(MrzError::InvalidCheckDigit(Field::Composite))
+        );
+        assert_eq!(
+            with_line("AA12345678SWE9001011M30010199001010017<<<<1"),
+            Err(MrzError::InvalidFormat)
+        );
+        assert_eq!(
+            with_line("aa12345678SWE9001011M30010199001010017<<<<12"),
+            Err(MrzError::InvalidFormat)
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Check personal identity numbers against SPAR, the Swedish population register, to complement
+//! offline validation with an authoritative lookup.
+//!
+//! [RegistryClient] is implemented by [SparClient], which queries the SPAR web service
+//! (`PersonsokningFraga`, version 2021.1) over SOAP, and can be implemented for other registers or
+//! mocks in tests. SPAR requires an agreement with Skatteverket and authenticates with a client
+//! certificate, so the [reqwest::Client] is created by the caller with the certificate.
+//!
+//! ```no_run
+//! # async fn lookup() -> Result<(), Box<dyn std::error::Error>> {
+//! use personnummer::{
+//!     online::{RegistryClient, SparClient},
+//!     Personnummer,
+//! };
+//!
+//! let identity = reqwest::Identity::from_pem(&std::fs::read("spar.pem")?)?;
+//! let client = reqwest::Client::builder().identity(identity).build()?;
+//! let spar = SparClient::new(client, "500243", "637");
+//!
+//! let pnr = Personnummer::parse("19900101-0017")?;
+//!
+//! match spar.lookup(&pnr).await? {
+//!     Some(registration) if registration.protected_identity => println!("protected identity"),
+//!     Some(_) => println!("registered"),
+//!     None => println!("not registered"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{FormatOptions, Personnummer};
+
+use std::{fmt, future::Future};
+
+/// [Registration] is the registration of a personal identity number in the population register.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Registration {
+    /// The person has a protected identity (sekretessmarkering).
+    pub protected_identity: bool,
+    /// The person has a protected address (skyddad folkbokföring).
+    pub protected_address: bool,
+    /// The person is deregistered, e.g. deceased or emigrated.
+    pub deregistered: bool,
+}
+
+/// [OnlineError] describes why a lookup failed.
+#[derive(Debug)]
+pub enum OnlineError {
+    /// The request failed.
+    Http(reqwest::Error),
+    /// The service responded with a SOAP fault, holds the fault string.
+    Fault(String),
+    /// The response couldn't be understood.
+    InvalidResponse,
+}
+
+impl fmt::Display for OnlineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OnlineError::Http(e) => write!(f, "Request failed: {}", e),
+            OnlineError::Fault(fault) => write!(f, "Lookup failed: {}", fault),
+            OnlineError::InvalidResponse => write!(f, "Invalid response"),
+        }
+    }
+}
+
+impl std::error::Error for OnlineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OnlineError::Http(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for OnlineError {
+    fn from(e: reqwest::Error) -> Self {
+        OnlineError::Http(e)
+    }
+}
+
+/// [RegistryClient] looks up personal identity numbers in a population register.
+pub trait RegistryClient {
+    /// Returns the [Registration] of the personal identity number, or `None` if it's not
+    /// registered.
+    fn lookup(
+        &self,
+        pnr: &Personnummer,
+    ) -> impl Future<Output = Result<Option<Registration>, OnlineError>> + Send;
+}
+
+/// [SparClient] is a [RegistryClient] for the SPAR web service.
+#[derive(Debug, Clone)]
+pub struct SparClient {
+    client: reqwest::Client,
+    url: String,
+    customer_number: String,
+    assignment_id: String,
+}
+
+impl SparClient {
+    /// The URL of the production environment.
+    pub const PRODUCTION_URL: &'static str =
+        "https://ext-ws.statenspersonadressregister.se/2021.1/";
+
+    /// The URL of the customer test environment.
+    pub const TEST_URL: &'static str = "https://kt-ext-ws.statenspersonadressregister.se/2021.1/";
+
+    /// Returns a new [SparClient] for the production environment. The client must be configured
+    /// with the client certificate, the customer number and assignment id are given by SPAR.
+    pub fn new(client: reqwest::Client, customer_number: &str, assignment_id: &str) -> Self {
+        SparClient {
+            client,
+            url: SparClient::PRODUCTION_URL.to_string(),
+            customer_number: customer_number.to_string(),
+            assignment_id: assignment_id.to_string(),
+        }
+    }
+
+    /// Use another URL, e.g. [SparClient::TEST_URL].
+    pub fn with_url(self, url: &str) -> Self {
+        SparClient {
+            url: url.to_string(),
+            ..self
+        }
+    }
+
+    /// Returns the SOAP request for the personal identity number.
+    fn request(&self, pnr: &Personnummer) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/" xmlns:per="http://statenspersonadressregister.se/schema/personsok/2021.1/personsokningfraga" xmlns:iden="http://statenspersonadressregister.se/schema/komponent/metadata/identifieringsinformationWs-1.1" xmlns:sok="http://statenspersonadressregister.se/schema/komponent/sok/personsokningsokparametrar-1.1" xmlns:pid="http://statenspersonadressregister.se/schema/komponent/person/person-1.2">
+  <soapenv:Body>
+    <per:SPARPersonsokningFraga>
+      <iden:Identifieringsinformation>
+        <iden:KundNrLeveransMottagare>{customer}</iden:KundNrLeveransMottagare>
+        <iden:KundNrSlutkund>{customer}</iden:KundNrSlutkund>
+        <iden:UppdragId>{assignment}</iden:UppdragId>
+        <iden:SlutAnvandarId>personnummer</iden:SlutAnvandarId>
+      </iden:Identifieringsinformation>
+      <sok:PersonsokningFraga>
+        <pid:IdNummer>{pnr}</pid:IdNummer>
+      </sok:PersonsokningFraga>
+    </per:SPARPersonsokningFraga>
+  </soapenv:Body>
+</soapenv:Envelope>"#,
+            customer = escape(&self.customer_number),
+            assignment = escape(&self.assignment_id),
+            pnr = pnr.format_with(FormatOptions::twelve_digits()),
+        )
+    }
+}
+
+impl RegistryClient for SparClient {
+    fn lookup(
+        &self,
+        pnr: &Personnummer,
+    ) -> impl Future<Output = Result<Option<Registration>, OnlineError>> + Send {
+        let request = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", "text/xml; charset=utf-8")
+            .body(self.request(pnr));
+
+        async move {
+            let body = request.send().await?.text().await?;
+
+            parse_response(&body)
+        }
+    }
+}
+
+/// Parse a SPAR response. A person that isn't registered gives a response without any
+/// `PersonsokningSvarsPost`.
+fn parse_response(body: &str) -> Result<Option<Registration>, OnlineError> {
+    if let Some(fault) = element(body, "faultstring") {
+        return Err(OnlineError::Fault(fault.trim().to_string()));
+    }
+
+    if element(body, "SPARPersonsokningSvar").is_none() {
+        return Err(OnlineError::InvalidResponse);
+    }
+
+    let Some(post) = element(body, "PersonsokningSvarsPost") else {
+        return Ok(None);
+    };
+
+    Ok(Some(Registration {
+        protected_identity: element(post, "Sekretessmarkering") == Some("J"),
+        protected_address: element(post, "SkyddadFolkbokforing") == Some("J"),
+        deregistered: element(post, "Avregistrering").is_some(),
+    }))
+}
+
+/// Returns the content of the first element with the local name, ignoring any namespace prefix.
+fn element<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = xml;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+
+        let end = rest.find('>')?;
+        let tag = &rest[..end];
+        let tag_name = tag
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches('/');
+        let local = tag_name.rsplit(':').next().unwrap_or_default();
+
+        if local != name || tag.starts_with('/') {
+            continue;
+        }
+
+        let content = &rest[end + 1..];
+
+        if tag.ends_with('/') {
+            return Some("");
+        }
+
+        let close = content.find(&format!("</{}>", tag_name))?;
+
+        return Some(&content[..close]);
+    }
+
+    None
+}
+
+/// Escape the characters with special meaning in XML.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(post: &str) -> String {
+        format!(
+            r#"<S:Envelope xmlns:S="http://schemas.xmlsoap.org/soap/envelope/"><S:Body><ns2:SPARPersonsokningSvar xmlns:ns2="http://statenspersonadressregister.se/schema/personsok/2021.1/personsokningsvar">{}</ns2:SPARPersonsokningSvar></S:Body></S:Envelope>"#,
+            post
+        )
+    }
+
+    #[test]
+    fn test_request() {
+        let client = SparClient::new(reqwest::Client::new(), "500243", "<637>");
+        let request = client.request(&Personnummer::parse("19900101-0017").unwrap());
+
+        assert!(request.contains("<pid:IdNummer>199001010017</pid:IdNummer>"));
+        assert!(request.contains("<iden:KundNrSlutkund>500243</iden:KundNrSlutkund>"));
+        assert!(request.contains("<iden:UppdragId>&lt;637&gt;</iden:UppdragId>"));
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let registered = response(
+            "<ns3:PersonsokningSvarsPost><ns4:PersonId><ns4:IdNummer>199001010017</ns4:IdNummer>\
+             </ns4:PersonId><ns5:Sekretessmarkering>N</ns5:Sekretessmarkering>\
+             <ns5:SkyddadFolkbokforing>N</ns5:SkyddadFolkbokforing></ns3:PersonsokningSvarsPost>",
+        );
+
+        assert_eq!(
+            parse_response(&registered).unwrap(),
+            Some(Registration::default())
+        );
+
+        let protected = response(
+            "<ns3:PersonsokningSvarsPost><ns5:Sekretessmarkering>J</ns5:Sekretessmarkering>\
+             <ns5:SkyddadFolkbokforing>J</ns5:SkyddadFolkbokforing>\
+             <ns5:Avregistrering><ns5:AvregistreringsorsakKod>AV</ns5:AvregistreringsorsakKod>\
+             </ns5:Avregistrering></ns3:PersonsokningSvarsPost>",
+        );
+
+        assert_eq!(
+            parse_response(&protected).unwrap(),
+            Some(Registration {
+                protected_identity: true,
+                protected_address: true,
+                deregistered: true,
+            })
+        );
+
+        assert_eq!(parse_response(&response("")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_response_errors() {
+        let fault = "<S:Envelope><S:Body><S:Fault><faultcode>S:Client</faultcode>\
+                     <faultstring>Ogiltigt kundnummer</faultstring></S:Fault></S:Body></S:Envelope>";
+
+        assert!(matches!(
+            parse_response(fault),
+            Err(OnlineError::Fault(f)) if f == "Ogiltigt kundnummer"
+        ));
+        assert!(matches!(
+            parse_response("<html>Not found</html>"),
+            Err(OnlineError::InvalidResponse)
+        ));
+    }
+
+    #[test]
+    fn test_element() {
+        let xml = r#"<a:Root x="1"><a:Empty/><b:Value id="2">J</b:Value></a:Root>"#;
+
+        assert_eq!(element(xml, "Value"), Some("J"));
+        assert_eq!(element(xml, "Empty"), Some(""));
+        assert_eq!(element(xml, "Missing"), None);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::Personnummer;
+
+/// The maximum number of digits in a pattern, the long format without separator.
+const MAX_DIGITS: usize = 12;
+
+impl Personnummer {
+    /// Returns true if the [Personnummer] matches a partial or masked personal identity number,
+    /// e.g. `800101-****`, `19800101-XXXX` or only the last four digits `2931`. Digits must match
+    /// and `*`, `X` or `x` match any digit. Patterns without century don't check the century and
+    /// the separator isn't checked, so a masked number copied from anywhere matches. Patterns that
+    /// don't have 4, 10 or 12 positions never match.
+    pub fn matches_partial(&self, pattern: &str) -> bool {
+        let mut positions = [None; MAX_DIGITS];
+        let mut len = 0;
+        let mut separator = None;
+
+        for c in pattern.trim().chars() {
+            let position = match c {
+                '0'..='9' => Some(c as u8 - b'0'),
+                '*' | 'X' | 'x' => None,
+                '-' | '+' if separator.is_none() && (len == 6 || len == 8) => {
+                    separator = Some(len);
+                    continue;
+                }
+                _ => return false,
+            };
+
+            if len == MAX_DIGITS {
+                return false;
+            }
+
+            positions[len] = position;
+            len += 1;
+        }
+
+        if !matches!(len, 4 | 10 | 12) || separator.is_some_and(|at| at + 4 != len) {
+            return false;
+        }
+
+        let value = self.to_u64();
+
+        positions[..len]
+            .iter()
+            .rev()
+            .enumerate()
+            .all(|(pos, digit)| {
+                digit.is_none_or(|d| u64::from(d) == value / 10u64.pow(pos as u32) % 10)
+            })
+    }
+}
+
+#[cfg(all(test, feature = "clock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_partial() {
+        let pnr = Personnummer::new("19800101-3294").unwrap();
+
+        for pattern in [
+            "800101-****",
+            "800101-3294",
+            "8001013294",
+            "19800101-XXXX",
+            "1980010132xx",
+            "******-3294",
+            "800101+****",
+            "3294",
+            " 3294 ",
+            "**94",
+        ] {
+            assert!(pnr.matches_partial(pattern), "{}", pattern);
+        }
+
+        for pattern in [
+            "800102-****",
+            "19810101-****",
+            "3295",
+            "800101-***",
+            "800101-*****",
+            "80-0101****",
+            "800101--****",
+            "800101 ****",
+            "",
+        ] {
+            assert!(!pnr.matches_partial(pattern), "{}", pattern);
+        }
+
+        let coordination = Personnummer::new("800161-3294").unwrap();
+
+        assert!(coordination.matches_partial("800161-****"));
+        assert!(!coordination.matches_partial("800101-****"));
+        assert!(coordination.matches_partial(&coordination.masked().to_string()));
+    }
+}
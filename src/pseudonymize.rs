@@ -0,0 +1,100 @@
+use crate::Personnummer;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use alloc::format;
+use core::fmt;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// [PseudonymizedId] is a stable keyed token for a [Personnummer] created with
+/// [Personnummer::pseudonymize()]. The same personal identity number and key always gives the same
+/// token regardless of the format the number was parsed from, but the number can't be derived
+/// from the token without the key.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PseudonymizedId([u8; 32]);
+
+impl PseudonymizedId {
+    /// Returns the raw bytes of the token.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Verify that the token was created from the [Personnummer] with the given key. The
+    /// comparison is done in constant time.
+    pub fn verify(&self, pnr: &Personnummer, key: &[u8]) -> bool {
+        mac(pnr, key).verify_slice(&self.0).is_ok()
+    }
+}
+
+impl From<[u8; 32]> for PseudonymizedId {
+    fn from(bytes: [u8; 32]) -> Self {
+        PseudonymizedId(bytes)
+    }
+}
+
+/// Displays the token as lower case hex.
+impl fmt::Display for PseudonymizedId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for b in self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PseudonymizedId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PseudonymizedId({})", self)
+    }
+}
+
+impl Personnummer {
+    /// Returns a [PseudonymizedId] which is the HMAC-SHA256 of the normalized personal identity
+    /// number using the given key. Use this to join datasets without storing the raw number.
+    pub fn pseudonymize(&self, key: &[u8]) -> PseudonymizedId {
+        PseudonymizedId(mac(self, key).finalize().into_bytes().into())
+    }
+}
+
+/// Returns the HMAC updated with the twelve digit format of the [Personnummer].
+fn mac(pnr: &Personnummer, key: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(format!("{:012}", pnr.to_u64()).as_bytes());
+
+    mac
+}
+
+#[cfg(all(test, feature = "clock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pseudonymize() {
+        let key = b"secret";
+
+        let a = Personnummer::new("19900101-0017")
+            .unwrap()
+            .pseudonymize(key);
+        let b = Personnummer::new("9001010017").unwrap().pseudonymize(key);
+        let c = Personnummer::new("19900101-0017")
+            .unwrap()
+            .pseudonymize(b"other");
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.to_string().len(), 64);
+    }
+
+    #[test]
+    fn test_verify() {
+        let pnr = Personnummer::new("19900101-0017").unwrap();
+        let id = pnr.pseudonymize(b"secret");
+
+        assert!(id.verify(&pnr, b"secret"));
+        assert!(!id.verify(&pnr, b"other"));
+        assert!(!id.verify(&Personnummer::new("800101-3294").unwrap(), b"secret"));
+    }
+}
@@ -0,0 +1,141 @@
+//! Python bindings through PyO3. The module is named `personnummer` and is built with `maturin`,
+//! see `pyproject.toml`.
+//!
+//! ```python
+//! import personnummer
+//!
+//! pnr = personnummer.parse("900101-0017")
+//! print(pnr.format(long=True), pnr.get_age(), pnr.gender())
+//!
+//! df["valid"] = personnummer.valid_all(df["pnr"].tolist())
+//! ```
+
+use crate::{parse_valid, quick_valid, Personnummer};
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+/// [PyPersonnummer] is the Python `Personnummer` class wrapping a [Personnummer].
+#[pyclass(name = "Personnummer", module = "personnummer", frozen)]
+pub struct PyPersonnummer(Personnummer);
+
+#[pymethods]
+impl PyPersonnummer {
+    /// Parse a personal identity number, raises `ValueError` for invalid input and dates but not
+    /// for an invalid checksum, the same as [Personnummer::new()]. Use `valid()` to check it.
+    #[new]
+    fn new(pnr: &str) -> PyResult<Self> {
+        Personnummer::new(pnr)
+            .map(PyPersonnummer)
+            .map_err(value_error)
+    }
+
+    /// Check if the personal identity number is valid.
+    fn valid(&self) -> bool {
+        self.0.valid()
+    }
+
+    /// Returns the short format, or the long format if `long` is true.
+    #[pyo3(signature = (long = false))]
+    fn format(&self, long: bool) -> String {
+        let formatted = self.0.format();
+
+        if long {
+            formatted.long()
+        } else {
+            formatted.short()
+        }
+    }
+
+    /// Returns the current age of the person.
+    fn get_age(&self) -> i32 {
+        self.0.get_age()
+    }
+
+    /// Returns the gender of the person, `female` or `male`.
+    fn gender(&self) -> String {
+        self.0.gender().to_string()
+    }
+
+    fn is_female(&self) -> bool {
+        self.0.is_female()
+    }
+
+    fn is_male(&self) -> bool {
+        self.0.is_male()
+    }
+
+    fn is_coordination_number(&self) -> bool {
+        self.0.is_coordination_number()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Personnummer('{}')", self.0.masked())
+    }
+}
+
+/// Parse a valid personal identity number, raises `ValueError` if it isn't valid.
+#[pyfunction]
+fn parse(pnr: &str) -> PyResult<PyPersonnummer> {
+    parse_valid(pnr).map(PyPersonnummer).map_err(value_error)
+}
+
+/// Check if the input is a valid personal identity number.
+#[pyfunction]
+fn valid(pnr: &str) -> bool {
+    quick_valid(pnr)
+}
+
+/// Check if each input is a valid personal identity number. The validation runs without holding
+/// the GIL which makes it suitable for large columns.
+#[pyfunction]
+fn valid_all(py: Python<'_>, pnrs: Vec<String>) -> Vec<bool> {
+    py.detach(|| pnrs.iter().map(|p| quick_valid(p)).collect())
+}
+
+/// The `personnummer` Python module.
+#[pymodule]
+fn personnummer(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyPersonnummer>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(valid, m)?)?;
+    m.add_function(wrap_pyfunction!(valid_all, m)?)?;
+
+    Ok(())
+}
+
+/// Map a parse error to a Python `ValueError`.
+fn value_error(e: crate::PersonnummerError) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_personnummer() {
+        let p = PyPersonnummer::new("800161-3291").unwrap();
+
+        assert!(p.valid());
+        assert_eq!(p.format(false), "800161-3291");
+        assert_eq!(p.format(true), "19800161-3291");
+        assert_eq!(p.gender(), "male");
+        assert!(p.is_coordination_number());
+        assert_eq!(p.__repr__(), "Personnummer('19800161-XXXX')");
+
+        assert!(!PyPersonnummer::new("900101-0018").unwrap().valid());
+        assert!(PyPersonnummer::new("invalid").is_err());
+    }
+
+    #[test]
+    fn test_functions() {
+        assert!(parse("900101-0017").is_ok());
+        assert!(parse("900101-0018").is_err());
+        assert!(valid("900101-0017"));
+        assert!(!valid("900101-0018"));
+    }
+}
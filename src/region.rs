@@ -0,0 +1,157 @@
+use crate::Personnummer;
+
+use core::fmt;
+
+/// The last year of birth where the serial encodes the county of registration.
+const LAST_REGION_YEAR: i32 = 1989;
+
+/// [BirthRegion] is the county (län) where a personal identity number was issued. Up until 1990
+/// the first two digits of the serial encoded the county, the counties are the ones that existed
+/// at the time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BirthRegion {
+    Stockholm,
+    Uppsala,
+    Sodermanland,
+    Ostergotland,
+    Jonkoping,
+    Kronoberg,
+    Kalmar,
+    Gotland,
+    Blekinge,
+    Kristianstad,
+    Malmohus,
+    Halland,
+    GoteborgOchBohus,
+    Alvsborg,
+    Skaraborg,
+    Varmland,
+    Orebro,
+    Vastmanland,
+    Kopparberg,
+    Gavleborg,
+    Vasternorrland,
+    Jamtland,
+    Vasterbotten,
+    Norrbotten,
+    /// Extra numbers not bound to a county (65 and 74).
+    Extra,
+    /// Extra numbers and people immigrated to Sweden (93 to 99).
+    Immigrated,
+}
+
+impl BirthRegion {
+    /// Returns the [BirthRegion] for the first two digits of a serial.
+    fn from_serial(serial: u32) -> Option<Self> {
+        let region = match serial / 10 {
+            0..=13 => BirthRegion::Stockholm,
+            14..=15 => BirthRegion::Uppsala,
+            16..=18 => BirthRegion::Sodermanland,
+            19..=23 => BirthRegion::Ostergotland,
+            24..=26 => BirthRegion::Jonkoping,
+            27..=28 => BirthRegion::Kronoberg,
+            29..=31 => BirthRegion::Kalmar,
+            32 => BirthRegion::Gotland,
+            33..=34 => BirthRegion::Blekinge,
+            35..=38 => BirthRegion::Kristianstad,
+            39..=45 => BirthRegion::Malmohus,
+            46..=47 => BirthRegion::Halland,
+            48..=54 => BirthRegion::GoteborgOchBohus,
+            55..=58 => BirthRegion::Alvsborg,
+            59..=61 => BirthRegion::Skaraborg,
+            62..=64 => BirthRegion::Varmland,
+            65 | 74 => BirthRegion::Extra,
+            66..=68 => BirthRegion::Orebro,
+            69..=70 => BirthRegion::Vastmanland,
+            71..=73 => BirthRegion::Kopparberg,
+            75..=77 => BirthRegion::Gavleborg,
+            78..=81 => BirthRegion::Vasternorrland,
+            82..=84 => BirthRegion::Jamtland,
+            85..=88 => BirthRegion::Vasterbotten,
+            89..=92 => BirthRegion::Norrbotten,
+            93..=99 => BirthRegion::Immigrated,
+            _ => return None,
+        };
+
+        Some(region)
+    }
+}
+
+/// Displays the Swedish name of the county.
+impl fmt::Display for BirthRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            BirthRegion::Stockholm => "Stockholms län",
+            BirthRegion::Uppsala => "Uppsala län",
+            BirthRegion::Sodermanland => "Södermanlands län",
+            BirthRegion::Ostergotland => "Östergötlands län",
+            BirthRegion::Jonkoping => "Jönköpings län",
+            BirthRegion::Kronoberg => "Kronobergs län",
+            BirthRegion::Kalmar => "Kalmar län",
+            BirthRegion::Gotland => "Gotlands län",
+            BirthRegion::Blekinge => "Blekinge län",
+            BirthRegion::Kristianstad => "Kristianstads län",
+            BirthRegion::Malmohus => "Malmöhus län",
+            BirthRegion::Halland => "Hallands län",
+            BirthRegion::GoteborgOchBohus => "Göteborgs och Bohus län",
+            BirthRegion::Alvsborg => "Älvsborgs län",
+            BirthRegion::Skaraborg => "Skaraborgs län",
+            BirthRegion::Varmland => "Värmlands län",
+            BirthRegion::Orebro => "Örebro län",
+            BirthRegion::Vastmanland => "Västmanlands län",
+            BirthRegion::Kopparberg => "Kopparbergs län",
+            BirthRegion::Gavleborg => "Gävleborgs län",
+            BirthRegion::Vasternorrland => "Västernorrlands län",
+            BirthRegion::Jamtland => "Jämtlands län",
+            BirthRegion::Vasterbotten => "Västerbottens län",
+            BirthRegion::Norrbotten => "Norrbottens län",
+            BirthRegion::Extra => "Extranummer",
+            BirthRegion::Immigrated => "Extranummer och invandrade",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl Personnummer {
+    /// Returns the [BirthRegion] where the personal identity number was issued. This is only
+    /// available for people born before 1990 and never for coordination numbers.
+    pub fn birth_region(&self) -> Option<BirthRegion> {
+        if self.year() > LAST_REGION_YEAR || self.is_coordination_number() {
+            return None;
+        }
+
+        BirthRegion::from_serial(self.serial())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[cfg(feature = "clock")]
+    #[test]
+    fn test_birth_region() {
+        let cases = [
+            ("19640823-3234", Some(BirthRegion::Gotland)),
+            ("19130401+2931", Some(BirthRegion::Kalmar)),
+            ("19800101-3294", Some(BirthRegion::Gotland)),
+            ("19510818-9167", Some(BirthRegion::Norrbotten)),
+            ("19891231-0017", Some(BirthRegion::Stockholm)),
+            ("19890101-6501", Some(BirthRegion::Extra)),
+            ("19890101-9901", Some(BirthRegion::Immigrated)),
+            ("19900101-0017", None),
+            ("800161-3294", None),
+        ];
+
+        for (pnr, region) in cases {
+            assert_eq!(Personnummer::new(pnr).unwrap().birth_region(), region);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BirthRegion::Ostergotland.to_string(), "Östergötlands län");
+    }
+}
@@ -0,0 +1,258 @@
+//! Locate and redact valid personal identity numbers in arbitrary text.
+//!
+//! A candidate is a run of 10 or 12 digits, or a run of 6 or 8 digits followed by a `-` or `+`
+//! separator and 4 digits. Candidates directly surrounded by other digits are ignored and only
+//! candidates passing [Personnummer::valid()] are reported.
+
+use crate::Personnummer;
+
+use std::ops::Range;
+
+/// Returns an iterator over all valid personal identity numbers in the text together with the
+/// byte range where they were found.
+pub fn find_all(text: &str) -> Matches<'_> {
+    Matches { text, pos: 0 }
+}
+
+/// [Matches] is an iterator over valid personal identity numbers in a text, created by
+/// [find_all()].
+pub struct Matches<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Iterator for Matches<'_> {
+    type Item = (Range<usize>, Personnummer);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+
+        while self.pos < bytes.len() {
+            let start = self.pos;
+
+            if !bytes[start].is_ascii_digit() {
+                self.pos += 1;
+                continue;
+            }
+
+            let digits_end = digit_run_end(bytes, start);
+            self.pos = digits_end;
+
+            let end = match digits_end - start {
+                10 | 12 => digits_end,
+                6 | 8 if matches!(bytes.get(digits_end), Some(b'-' | b'+')) => {
+                    let serial_end = digit_run_end(bytes, digits_end + 1);
+
+                    if serial_end - digits_end - 1 != 4 {
+                        continue;
+                    }
+
+                    self.pos = serial_end;
+                    serial_end
+                }
+                _ => continue,
+            };
+
+            if let Ok(pnr) = Personnummer::new(&self.text[start..end]) {
+                if pnr.valid() {
+                    return Some((start..end, pnr));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// [RedactStyle] selects what a personal identity number is replaced with by [redact()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedactStyle {
+    /// Replace every digit with `X` and keep the separator, e.g. `XXXXXXXX-XXXX`.
+    Full,
+    /// Keep the year as written and replace all other digits with `X`, e.g. `1990XXXX-XXXX`.
+    KeepBirthYear,
+    /// Replace the number with a stable pseudonym created with the key, see
+    /// [Personnummer::pseudonymize()].
+    #[cfg(feature = "pseudonymize")]
+    Pseudonym(Vec<u8>),
+}
+
+/// [Replacement] describes a single personal identity number replaced by [redact()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement {
+    /// The byte range of the personal identity number in the original text.
+    pub original: Range<usize>,
+    /// The byte range of the replacement in the redacted text.
+    pub redacted: Range<usize>,
+}
+
+/// [Redacted] holds the text with all valid personal identity numbers replaced and a report of
+/// each replacement made, created by [redact()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redacted {
+    /// The redacted text.
+    pub text: String,
+    /// All replacements in the order they occur in the text.
+    pub replacements: Vec<Replacement>,
+}
+
+/// Returns the text with all valid personal identity numbers, as found by [find_all()], replaced
+/// according to the [RedactStyle].
+pub fn redact(text: &str, style: &RedactStyle) -> Redacted {
+    let mut redacted = String::with_capacity(text.len());
+    let mut replacements = Vec::new();
+    let mut last = 0;
+
+    for (range, _pnr) in find_all(text) {
+        redacted.push_str(&text[last..range.start]);
+
+        let original = &text[range.clone()];
+        let start = redacted.len();
+
+        match style {
+            RedactStyle::Full => mask_digits(original, 0, &mut redacted),
+            RedactStyle::KeepBirthYear => {
+                let year_digits = if original.len() >= 12 { 4 } else { 2 };
+                mask_digits(original, year_digits, &mut redacted);
+            }
+            #[cfg(feature = "pseudonymize")]
+            RedactStyle::Pseudonym(key) => {
+                redacted.push_str(&_pnr.pseudonymize(key).to_string());
+            }
+        }
+
+        replacements.push(Replacement {
+            original: range.clone(),
+            redacted: start..redacted.len(),
+        });
+
+        last = range.end;
+    }
+
+    redacted.push_str(&text[last..]);
+
+    Redacted {
+        text: redacted,
+        replacements,
+    }
+}
+
+/// Push the input with all but the first `keep` digits replaced with `X`.
+fn mask_digits(input: &str, keep: usize, out: &mut String) {
+    for (i, c) in input.chars().enumerate() {
+        if i >= keep && c.is_ascii_digit() {
+            out.push('X');
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+/// Returns the index of the first non digit at or after `start`.
+fn digit_run_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|b| !b.is_ascii_digit())
+        .map_or(bytes.len(), |p| start + p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_all() {
+        let text = "Kund 19900101-0017 ringde om 8001013294, ref 196408233234 och 130401+2931.";
+
+        let found = find_all(text)
+            .map(|(range, pnr)| (&text[range], pnr.format().long()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            found,
+            vec![
+                ("19900101-0017", "19900101-0017".to_string()),
+                ("8001013294", "19800101-3294".to_string()),
+                ("196408233234", "19640823-3234".to_string()),
+                ("130401+2931", "19130401-2931".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_all_ignores_invalid() {
+        let cases = vec![
+            "",
+            "no numbers here",
+            "19900101-0018",
+            "119900101-0017",
+            "19900101-00171",
+            "19900101-001",
+            "order 1234567890",
+            "19900101 0017",
+        ];
+
+        for tc in cases {
+            assert_eq!(find_all(tc).count(), 0, "{}", tc);
+        }
+    }
+
+    #[test]
+    fn test_redact() {
+        let text = "a 19900101-0017 b 8001013294 c 19900101-0018";
+
+        let cases = vec![
+            (
+                RedactStyle::Full,
+                "a XXXXXXXX-XXXX b XXXXXXXXXX c 19900101-0018",
+            ),
+            (
+                RedactStyle::KeepBirthYear,
+                "a 1990XXXX-XXXX b 80XXXXXXXX c 19900101-0018",
+            ),
+        ];
+
+        for (style, expected) in cases {
+            let redacted = redact(text, &style);
+
+            assert_eq!(redacted.text, expected);
+            assert_eq!(
+                redacted.replacements,
+                vec![
+                    Replacement {
+                        original: 2..15,
+                        redacted: 2..15,
+                    },
+                    Replacement {
+                        original: 18..28,
+                        redacted: 18..28,
+                    },
+                ]
+            );
+        }
+    }
+
+    #[cfg(feature = "pseudonymize")]
+    #[test]
+    fn test_redact_pseudonym() {
+        let key = b"secret";
+        let redacted = redact("id: 900101-0017.", &RedactStyle::Pseudonym(key.to_vec()));
+        let pseudonym = Personnummer::new("19900101-0017")
+            .unwrap()
+            .pseudonymize(key)
+            .to_string();
+
+        assert_eq!(redacted.text, format!("id: {}.", pseudonym));
+        assert_eq!(redacted.replacements[0].original, 4..15);
+        assert_eq!(redacted.replacements[0].redacted, 4..68);
+    }
+
+    #[test]
+    fn test_find_all_offsets() {
+        let text = "åäö:900101-0017";
+        let (range, _) = find_all(text).next().unwrap();
+
+        assert_eq!(range, 7..18);
+        assert_eq!(&text[range], "900101-0017");
+    }
+}
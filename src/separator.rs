@@ -0,0 +1,113 @@
+use crate::{age::age_exact, Date, LeapDayPolicy};
+
+use core::fmt;
+
+/// [Separator] is the character between the birth date and the serial in the ten digit format.
+/// It's `-` for persons younger than 100 years and `+` for persons that are 100 years or older,
+/// which is what makes the century of a ten digit format unambiguous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Separator {
+    /// `-`, used for persons younger than 100 years.
+    Minus,
+    /// `+`, used for persons that are 100 years or older.
+    Plus,
+}
+
+impl Separator {
+    /// Returns the [Separator] for a person of the given age.
+    pub fn for_age(age: u32) -> Self {
+        if age >= 100 {
+            Separator::Plus
+        } else {
+            Separator::Minus
+        }
+    }
+
+    /// Returns the [Separator] for a person born at the birth date at the reference date. The
+    /// age is calculated the same way as [crate::Personnummer::age_at()].
+    pub fn at(birth_date: impl Into<Date>, date: impl Into<Date>) -> Self {
+        let age = age_exact(birth_date.into(), date.into(), LeapDayPolicy::default());
+
+        Separator::for_age(age.years)
+    }
+
+    /// Returns the separator as a [char].
+    pub fn as_char(self) -> char {
+        match self {
+            Separator::Minus => '-',
+            Separator::Plus => '+',
+        }
+    }
+}
+
+impl fmt::Display for Separator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Write::write_char(f, self.as_char())
+    }
+}
+
+impl TryFrom<char> for Separator {
+    type Error = char;
+
+    /// Returns the [Separator] for `-` or `+`, or the character back if it's not a separator.
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '-' => Ok(Separator::Minus),
+            '+' => Ok(Separator::Plus),
+            _ => Err(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::from_ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_for_age() {
+        assert_eq!(Separator::for_age(0), Separator::Minus);
+        assert_eq!(Separator::for_age(99), Separator::Minus);
+        assert_eq!(Separator::for_age(100), Separator::Plus);
+    }
+
+    #[test]
+    fn test_at() {
+        let birth = date(1913, 4, 1);
+
+        assert_eq!(Separator::at(birth, date(2013, 3, 31)), Separator::Minus);
+        assert_eq!(Separator::at(birth, date(2013, 4, 1)), Separator::Plus);
+        assert_eq!(Separator::at(birth, date(1900, 1, 1)), Separator::Minus);
+
+        // Born on a leap day and turning 100 in a non leap year, which happens on the first of
+        // March.
+        let birth = date(2000, 2, 29);
+
+        assert_eq!(Separator::at(birth, date(2100, 2, 28)), Separator::Minus);
+        assert_eq!(Separator::at(birth, date(2100, 3, 1)), Separator::Plus);
+
+        let pnr = crate::Personnummer::new_at("19130401+2931", date(2013, 4, 1)).unwrap();
+
+        for reference in [date(2013, 3, 31), date(2013, 4, 1)] {
+            let short = pnr.format_at(reference).short();
+
+            assert_eq!(
+                short.chars().nth(6),
+                Some(Separator::at(pnr.birth_date(), reference).as_char())
+            );
+        }
+    }
+
+    #[test]
+    fn test_char() {
+        assert_eq!(Separator::try_from('+'), Ok(Separator::Plus));
+        assert_eq!(Separator::try_from('_'), Err('_'));
+        assert_eq!(Separator::Minus.to_string(), "-");
+    }
+}
@@ -0,0 +1,199 @@
+use crate::Personnummer;
+#[cfg(feature = "clock")]
+use crate::PersonnummerError;
+
+use alloc::collections::{btree_set, BTreeSet};
+
+/// [PersonnummerSet] is a deduplicating collection of personal identity numbers. Each number is
+/// stored normalized as a single integer, see [Personnummer::to_u64()], so the same person given
+/// in different formats, e.g. `19900101-0017`, `900101-0017` and `199001010017`, only occupies one
+/// entry. Iteration is done in canonical order which is the order of the long format.
+#[derive(Default)]
+pub struct PersonnummerSet {
+    inner: BTreeSet<u64>,
+}
+
+impl PersonnummerSet {
+    /// Returns a new empty [PersonnummerSet].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a [Personnummer] into the set. Returns `true` if the number wasn't already present.
+    pub fn insert(&mut self, pnr: &Personnummer) -> bool {
+        self.inner.insert(pnr.to_u64())
+    }
+
+    /// Parse and insert a valid personal identity number into the set, see
+    /// [Personnummer::parse()]. Returns `true` if the number wasn't already present.
+    #[cfg(feature = "clock")]
+    pub fn insert_str(&mut self, pnr: &str) -> Result<bool, PersonnummerError> {
+        Ok(self.insert(&Personnummer::parse(pnr)?))
+    }
+
+    /// Parse and insert all personal identity numbers from an iterator. Returns the number of new
+    /// entries added to the set. Parsing stops at the first invalid input and the error is
+    /// returned, any numbers parsed before the error are kept in the set.
+    #[cfg(feature = "clock")]
+    pub fn insert_all_str<'a, I>(&mut self, pnrs: I) -> Result<usize, PersonnummerError>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut added = 0;
+
+        for pnr in pnrs {
+            if self.insert_str(pnr)? {
+                added += 1;
+            }
+        }
+
+        Ok(added)
+    }
+
+    /// Check if the set contains the [Personnummer].
+    pub fn contains(&self, pnr: &Personnummer) -> bool {
+        self.inner.contains(&pnr.to_u64())
+    }
+
+    /// Check if the set contains the personal identity number. Input that can't be parsed is never
+    /// contained in the set.
+    #[cfg(feature = "clock")]
+    pub fn contains_str(&self, pnr: &str) -> bool {
+        Personnummer::new(pnr)
+            .map(|p| self.contains(&p))
+            .unwrap_or(false)
+    }
+
+    /// Remove a [Personnummer] from the set. Returns `true` if the number was present.
+    pub fn remove(&mut self, pnr: &Personnummer) -> bool {
+        self.inner.remove(&pnr.to_u64())
+    }
+
+    /// Returns the number of unique personal identity numbers in the set.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the set contains no personal identity numbers.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Iterate over all personal identity numbers in the set in canonical order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.inner.iter(),
+        }
+    }
+}
+
+impl Extend<Personnummer> for PersonnummerSet {
+    fn extend<I: IntoIterator<Item = Personnummer>>(&mut self, iter: I) {
+        for pnr in iter {
+            self.insert(&pnr);
+        }
+    }
+}
+
+impl FromIterator<Personnummer> for PersonnummerSet {
+    fn from_iter<I: IntoIterator<Item = Personnummer>>(iter: I) -> Self {
+        let mut set = PersonnummerSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a PersonnummerSet {
+    type Item = Personnummer;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// [Iter] is an iterator over the personal identity numbers in a [PersonnummerSet], created by
+/// [PersonnummerSet::iter()].
+pub struct Iter<'a> {
+    inner: btree_set::Iter<'a, u64>,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Personnummer;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|v| Personnummer::from_u64(*v).expect("only packed numbers are stored"))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(all(test, feature = "clock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup_formats() {
+        let mut set = PersonnummerSet::new();
+
+        let added = set
+            .insert_all_str(vec![
+                "19900101-0017",
+                "900101-0017",
+                "199001010017",
+                "9001010017",
+                "800161-3291",
+                "19800161-3291",
+            ])
+            .unwrap();
+
+        assert_eq!(added, 2);
+        assert_eq!(set.len(), 2);
+        assert!(set.contains_str("900101-0017"));
+        assert!(set.contains_str("198001613291"));
+        assert!(!set.contains_str("800101-3294"));
+        assert!(!set.contains_str("not-a-pnr"));
+    }
+
+    #[test]
+    fn test_canonical_order() {
+        let set: PersonnummerSet = vec!["19900101-0017", "000101-0107", "19130401+2931"]
+            .into_iter()
+            .map(|p| Personnummer::new(p).unwrap())
+            .collect();
+
+        let long = set.iter().map(|p| p.format().long()).collect::<Vec<_>>();
+
+        assert_eq!(
+            long,
+            vec!["19130401-2931", "19900101-0017", "20000101-0107"]
+        );
+    }
+
+    #[test]
+    fn test_insert_invalid() {
+        let mut set = PersonnummerSet::new();
+
+        assert!(set.insert_str("19900101-0017").unwrap());
+        assert!(!set.insert_str("900101-0017").unwrap());
+        assert!(set
+            .insert_all_str(vec!["19900101-0017", "invalid"])
+            .is_err());
+        assert_eq!(
+            set.insert_str("19900101-0018"),
+            Err(PersonnummerError::InvalidChecksum {
+                expected: 7,
+                found: 8
+            })
+        );
+        assert_eq!(
+            set.insert_str("19900101-0000"),
+            Err(PersonnummerError::InvalidSerial)
+        );
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -0,0 +1,295 @@
+//! Aggregate demographics for a collection of personal identity numbers.
+
+use crate::{Date, Gender, Personnummer};
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// [Summary] holds the gender split, coordination numbers and age distribution of a collection
+/// of personal identity numbers. Ages are calculated at the reference date of the summary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    /// The date ages are calculated at.
+    pub date: Date,
+    /// The number of personal identity numbers.
+    pub count: usize,
+    /// The number of women.
+    pub female: usize,
+    /// The number of men.
+    pub male: usize,
+    /// The number of coordination numbers.
+    pub coordination: usize,
+    /// The number of persons of each age.
+    pub ages: BTreeMap<u32, usize>,
+}
+
+/// [AgeBucket] is the number of persons in an age range, created by [Summary::age_buckets()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AgeBucket {
+    /// The lowest age in the bucket.
+    pub min: u32,
+    /// The highest age in the bucket, `None` for the last bucket.
+    pub max: Option<u32>,
+    /// The number of persons in the bucket.
+    pub count: usize,
+}
+
+impl Summary {
+    /// Returns an empty [Summary] calculating ages at the current date.
+    #[cfg(feature = "clock")]
+    pub fn new() -> Self {
+        Summary::new_at(crate::today())
+    }
+
+    /// Returns an empty [Summary] calculating ages at the given date.
+    pub fn new_at(date: impl Into<Date>) -> Self {
+        Summary {
+            date: date.into(),
+            count: 0,
+            female: 0,
+            male: 0,
+            coordination: 0,
+            ages: BTreeMap::new(),
+        }
+    }
+
+    /// Returns a [Summary] of the personal identity numbers with ages calculated at the given
+    /// date. Use [Summary::from_iter()] to calculate ages at the current date.
+    pub fn from_iter_at<I>(pnrs: I, date: impl Into<Date>) -> Self
+    where
+        I: IntoIterator,
+        I::Item: core::borrow::Borrow<Personnummer>,
+    {
+        let mut summary = Summary::new_at(date);
+        summary.extend(pnrs);
+
+        summary
+    }
+
+    /// Add a personal identity number to the summary.
+    pub fn add(&mut self, pnr: &Personnummer) {
+        self.count += 1;
+
+        match pnr.gender() {
+            Gender::Female => self.female += 1,
+            Gender::Male => self.male += 1,
+        }
+
+        if pnr.is_coordination_number() {
+            self.coordination += 1;
+        }
+
+        *self.ages.entry(pnr.age_at(self.date)).or_default() += 1;
+    }
+
+    /// Returns the share of women, between 0 and 1, or `None` if the summary is empty.
+    pub fn female_ratio(&self) -> Option<f64> {
+        self.ratio(self.female)
+    }
+
+    /// Returns the share of coordination numbers, between 0 and 1, or `None` if the summary is
+    /// empty.
+    pub fn coordination_ratio(&self) -> Option<f64> {
+        self.ratio(self.coordination)
+    }
+
+    /// Returns the mean age, or `None` if the summary is empty.
+    pub fn mean_age(&self) -> Option<f64> {
+        let total = self
+            .ages
+            .iter()
+            .map(|(age, count)| *age as f64 * *count as f64)
+            .sum::<f64>();
+
+        (self.count > 0).then(|| total / self.count as f64)
+    }
+
+    /// Returns the median age, the mean of the two middle ages for an even count, or `None` if
+    /// the summary is empty.
+    pub fn median_age(&self) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let lower = self.nth_age((self.count - 1) / 2);
+        let upper = self.nth_age(self.count / 2);
+
+        Some((lower as f64 + upper as f64) / 2.0)
+    }
+
+    /// Returns the number of persons in each age range starting at the given ages, or `None` if the
+    /// ages aren't in strictly ascending order. Persons younger than the first age aren't counted.
+    ///
+    /// ```
+    /// use personnummer::{stats::Summary, Date, Personnummer};
+    ///
+    /// let date = Date::from_ymd(2020, 1, 1).unwrap();
+    /// let pnrs = ["19900101-0017", "20100101-0014"].map(|p| Personnummer::new_at(p, date).unwrap());
+    /// let summary = Summary::from_iter_at(pnrs, date);
+    /// let buckets = summary.age_buckets(&[0, 18, 65]).unwrap();
+    ///
+    /// assert_eq!(buckets[0].max, Some(17));
+    /// assert_eq!(buckets.iter().map(|b| b.count).collect::<Vec<_>>(), [1, 1, 0]);
+    /// ```
+    pub fn age_buckets(&self, bounds: &[u32]) -> Option<Vec<AgeBucket>> {
+        if !bounds.is_sorted_by(|a, b| a < b) {
+            return None;
+        }
+
+        let buckets = bounds
+            .iter()
+            .enumerate()
+            .map(|(idx, min)| {
+                let next = bounds.get(idx + 1).copied();
+                let count = self
+                    .ages
+                    .range(*min..next.unwrap_or(u32::MAX))
+                    .map(|(_, count)| count)
+                    .sum();
+
+                AgeBucket {
+                    min: *min,
+                    max: next.map(|next| next - 1),
+                    count,
+                }
+            })
+            .collect();
+
+        Some(buckets)
+    }
+
+    /// Returns the age at the index of the sorted ages.
+    fn nth_age(&self, n: usize) -> u32 {
+        let mut seen = 0;
+
+        for (age, count) in &self.ages {
+            seen += count;
+
+            if seen > n {
+                return *age;
+            }
+        }
+
+        unreachable!("index is less than the count")
+    }
+
+    fn ratio(&self, count: usize) -> Option<f64> {
+        (self.count > 0).then(|| count as f64 / self.count as f64)
+    }
+}
+
+#[cfg(feature = "clock")]
+impl Default for Summary {
+    fn default() -> Self {
+        Summary::new()
+    }
+}
+
+impl<P: core::borrow::Borrow<Personnummer>> Extend<P> for Summary {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, pnrs: I) {
+        for pnr in pnrs {
+            self.add(pnr.borrow());
+        }
+    }
+}
+
+/// Collects a [Summary] with ages calculated at the current date.
+#[cfg(feature = "clock")]
+impl<P: core::borrow::Borrow<Personnummer>> FromIterator<P> for Summary {
+    fn from_iter<I: IntoIterator<Item = P>>(pnrs: I) -> Self {
+        Summary::from_iter_at(pnrs, crate::today())
+    }
+}
+
+#[cfg(all(test, feature = "clock"))]
+mod tests {
+    use super::*;
+
+    fn summary() -> Summary {
+        let pnrs = [
+            "19900101-0017",
+            "19800161-3291",
+            "20100101-0022",
+            "19130401+2931",
+        ];
+
+        Summary::from_iter_at(
+            pnrs.iter().map(|p| Personnummer::parse(p).unwrap()),
+            Date::from_ymd(2020, 1, 1).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_summary() {
+        let summary = summary();
+
+        assert_eq!(summary.count, 4);
+        assert_eq!(summary.female, 1);
+        assert_eq!(summary.male, 3);
+        assert_eq!(summary.coordination, 1);
+        assert_eq!(summary.female_ratio(), Some(0.25));
+        assert_eq!(summary.coordination_ratio(), Some(0.25));
+        assert_eq!(
+            summary
+                .ages
+                .iter()
+                .map(|(a, c)| (*a, *c))
+                .collect::<Vec<_>>(),
+            vec![(10, 1), (30, 1), (40, 1), (106, 1)]
+        );
+        assert_eq!(summary.mean_age(), Some(46.5));
+        assert_eq!(summary.median_age(), Some(35.0));
+    }
+
+    #[test]
+    fn test_age_buckets() {
+        let buckets = summary().age_buckets(&[0, 18, 65]).unwrap();
+
+        assert_eq!(
+            buckets,
+            vec![
+                AgeBucket {
+                    min: 0,
+                    max: Some(17),
+                    count: 1
+                },
+                AgeBucket {
+                    min: 18,
+                    max: Some(64),
+                    count: 2
+                },
+                AgeBucket {
+                    min: 65,
+                    max: None,
+                    count: 1
+                },
+            ]
+        );
+
+        assert_eq!(summary().age_buckets(&[]), Some(vec![]));
+        assert_eq!(summary().age_buckets(&[18, 0]), None);
+        assert_eq!(summary().age_buckets(&[0, 18, 18]), None);
+    }
+
+    #[test]
+    fn test_empty() {
+        let summary = Summary::new_at(Date::from_ymd(2020, 1, 1).unwrap());
+
+        assert_eq!(summary.mean_age(), None);
+        assert_eq!(summary.median_age(), None);
+        assert_eq!(summary.female_ratio(), None);
+        assert!(summary
+            .age_buckets(&[0, 18])
+            .unwrap()
+            .iter()
+            .all(|b| b.count == 0));
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let pnrs = [Personnummer::parse("19900101-0017").unwrap()];
+        let summary = pnrs.iter().collect::<Summary>();
+
+        assert_eq!(summary.count, 1);
+        assert!(summary.mean_age().unwrap() >= 30.0);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::Personnummer;
+
+use alloc::{collections::BTreeSet, vec::Vec};
+
+/// The number of digits, excluding the century, that can be corrected.
+const DIGITS: u32 = 10;
+
+impl Personnummer {
+    /// Returns valid personal identity numbers that differ from this one by a single digit
+    /// substitution or by swapping two adjacent digits, the most common typing mistakes. The
+    /// century is never changed. Returns an empty list if the number is already valid.
+    pub fn suggest_corrections(&self) -> Vec<Personnummer> {
+        if self.valid() {
+            return Vec::new();
+        }
+
+        let value = self.to_u64();
+        let mut candidates = BTreeSet::new();
+
+        for pos in 0..DIGITS {
+            let current = digit(value, pos);
+
+            for replacement in 0..10 {
+                if replacement != current {
+                    candidates.insert(set_digit(value, pos, replacement));
+                }
+            }
+
+            if pos + 1 < DIGITS {
+                let next = digit(value, pos + 1);
+
+                if next != current {
+                    candidates.insert(set_digit(set_digit(value, pos, next), pos + 1, current));
+                }
+            }
+        }
+
+        candidates
+            .into_iter()
+            .filter_map(|v| Personnummer::from_u64(v).ok())
+            .filter(|p| p.valid())
+            .collect()
+    }
+}
+
+/// Returns the digit at the position counted from the right.
+fn digit(value: u64, pos: u32) -> u64 {
+    value / 10u64.pow(pos) % 10
+}
+
+/// Returns the value with the digit at the position counted from the right replaced.
+fn set_digit(value: u64, pos: u32, digit_value: u64) -> u64 {
+    let factor = 10u64.pow(pos);
+
+    value - digit(value, pos) * factor + digit_value * factor
+}
+
+#[cfg(all(test, feature = "clock"))]
+mod tests {
+    use super::*;
+
+    fn suggestions(pnr: &str) -> Vec<String> {
+        Personnummer::new(pnr)
+            .unwrap()
+            .suggest_corrections()
+            .iter()
+            .map(|p| p.format().long())
+            .collect()
+    }
+
+    #[test]
+    fn test_suggest_corrections() {
+        // Transposed serial digits.
+        assert!(suggestions("19900101-0107").contains(&"19900101-0017".to_string()));
+
+        // Mistyped control digit.
+        assert!(suggestions("19900101-0018").contains(&"19900101-0017".to_string()));
+
+        // Mistyped day.
+        assert!(suggestions("19900102-0017").contains(&"19900101-0017".to_string()));
+
+        for suggestion in suggestions("19900101-0018") {
+            assert!(Personnummer::new(&suggestion).unwrap().valid());
+        }
+    }
+
+    #[test]
+    fn test_suggest_corrections_valid() {
+        assert!(suggestions("19900101-0017").is_empty());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{Personnummer, PersonnummerSet};
+
+use std::io::{self, BufRead};
+
+/// [TestNumbers] holds the personal identity numbers Skatteverket publishes as reserved for
+/// testing. The list is updated by Skatteverket and not embedded in this crate, download it from
+/// the open data published by Skatteverket and load it with [TestNumbers::from_reader()].
+#[derive(Default)]
+pub struct TestNumbers {
+    numbers: PersonnummerSet,
+}
+
+impl TestNumbers {
+    /// Load test numbers from a reader with one personal identity number per line, which is the
+    /// format of the published CSV file. Lines that aren't a personal identity number, such as
+    /// the header, are skipped.
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut numbers = PersonnummerSet::new();
+
+        for line in reader.lines() {
+            if let Ok(pnr) = Personnummer::new(line?.trim()) {
+                numbers.insert(&pnr);
+            }
+        }
+
+        Ok(TestNumbers { numbers })
+    }
+
+    /// Check if the [Personnummer] is one of the test numbers.
+    pub fn contains(&self, pnr: &Personnummer) -> bool {
+        self.numbers.contains(pnr)
+    }
+
+    /// Returns the number of loaded test numbers.
+    pub fn len(&self) -> usize {
+        self.numbers.len()
+    }
+
+    /// Returns `true` if no test numbers are loaded.
+    pub fn is_empty(&self) -> bool {
+        self.numbers.is_empty()
+    }
+}
+
+impl FromIterator<Personnummer> for TestNumbers {
+    fn from_iter<I: IntoIterator<Item = Personnummer>>(iter: I) -> Self {
+        TestNumbers {
+            numbers: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Personnummer {
+    /// Check if the personal identity number is one of the [TestNumbers] published by
+    /// Skatteverket.
+    pub fn is_test_number(&self, test_numbers: &TestNumbers) -> bool {
+        test_numbers.contains(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_reader() {
+        let csv = "testpersonnummer\n199001010017\r\n\n196408233234\n";
+        let test_numbers = TestNumbers::from_reader(csv.as_bytes()).unwrap();
+
+        assert_eq!(test_numbers.len(), 2);
+        assert!(Personnummer::new("900101-0017")
+            .unwrap()
+            .is_test_number(&test_numbers));
+        assert!(!Personnummer::new("800101-3294")
+            .unwrap()
+            .is_test_number(&test_numbers));
+    }
+}
@@ -0,0 +1,67 @@
+//! Test vectors in the language-agnostic format shared by all personnummer implementations in the
+//! personnummer/meta repository.
+//!
+//! The vectors bundled with this crate are in `testdata/list.json`. The full list from the meta
+//! repository can be loaded with [from_reader()] to keep this crate in lockstep with the other
+//! implementations.
+
+use serde::Deserialize;
+
+use std::io;
+
+/// The test vectors bundled with the crate.
+const LIST: &str = include_str!("../testdata/list.json");
+
+/// [TestCase] is a single personal identity number in all formats together with the expected
+/// result when parsing it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TestCase {
+    /// The ten digit format as an integer.
+    pub integer: u64,
+    /// The twelve digit format without separator, e.g. `199001010017`.
+    pub long_format: String,
+    /// The ten digit format without separator, e.g. `9001010017`.
+    pub short_format: String,
+    /// The ten digit format with separator, e.g. `900101-0017`.
+    pub separated_format: String,
+    /// The twelve digit format with separator, e.g. `19900101-0017`.
+    pub separated_long: String,
+    /// If the personal identity number is valid.
+    pub valid: bool,
+    /// The type of number, `ssn` for personal identity numbers and `con` for coordination numbers.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// If the person is a male.
+    #[serde(rename = "isMale")]
+    pub is_male: bool,
+    /// If the person is a female.
+    #[serde(rename = "isFemale")]
+    pub is_female: bool,
+}
+
+impl TestCase {
+    /// Returns all formats of the personal identity number.
+    pub fn formats(&self) -> [&str; 4] {
+        [
+            &self.long_format,
+            &self.short_format,
+            &self.separated_format,
+            &self.separated_long,
+        ]
+    }
+
+    /// Check if the test case is a coordination number.
+    pub fn is_coordination_number(&self) -> bool {
+        self.kind == "con"
+    }
+}
+
+/// Returns the test vectors bundled with the crate.
+pub fn cases() -> Vec<TestCase> {
+    serde_json::from_str(LIST).expect("bundled test data is valid")
+}
+
+/// Read test vectors in the meta format, e.g. `testdata/list.json` from the meta repository.
+pub fn from_reader<R: io::Read>(reader: R) -> serde_json::Result<Vec<TestCase>> {
+    serde_json::from_reader(reader)
+}
@@ -0,0 +1,109 @@
+#[cfg(feature = "clock")]
+use crate::today;
+use crate::{Personnummer, PersonnummerError};
+
+/// [ValidationReport] holds the result of each check done when validating a [Personnummer], see
+/// [Personnummer::validation_report()]. The date is always valid for a parsed [Personnummer].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// The serial isn't 000.
+    pub serial: bool,
+    /// The control digit matches the Luhn checksum.
+    pub checksum: bool,
+    /// The birth date isn't in the future.
+    pub plausible: bool,
+}
+
+impl ValidationReport {
+    /// Check if all checks passed.
+    pub fn is_valid(&self) -> bool {
+        self.serial && self.checksum && self.plausible
+    }
+}
+
+impl Personnummer {
+    /// Validate a [Personnummer] and return the reason if it's invalid. Besides the checks done by
+    /// [Personnummer::valid()] this also requires the birth date to not be in the future.
+    #[cfg(feature = "clock")]
+    pub fn validate(&self) -> Result<(), PersonnummerError> {
+        self.validate_checksum()?;
+
+        if !self.validation_report().plausible {
+            return Err(PersonnummerError::FutureBirthDate);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the reason [Personnummer::valid()] is false, if any.
+    pub(crate) fn validate_checksum(&self) -> Result<(), PersonnummerError> {
+        if self.serial == 0 {
+            return Err(PersonnummerError::InvalidSerial);
+        }
+
+        let expected = self.checksum();
+
+        if expected != self.control {
+            return Err(PersonnummerError::InvalidChecksum {
+                expected,
+                found: self.control,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns a [ValidationReport] with the result of each check done by
+    /// [Personnummer::validate()].
+    #[cfg(feature = "clock")]
+    pub fn validation_report(&self) -> ValidationReport {
+        ValidationReport {
+            serial: self.serial > 0,
+            checksum: self.checksum() == self.control,
+            plausible: self.date <= today(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "clock"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let cases = vec![
+            ("19900101-0017", Ok(())),
+            ("800161-3291", Ok(())),
+            (
+                "19900101-0018",
+                Err(PersonnummerError::InvalidChecksum {
+                    expected: 7,
+                    found: 8,
+                }),
+            ),
+            ("19900101-0000", Err(PersonnummerError::InvalidSerial)),
+            ("29900101-0017", Err(PersonnummerError::FutureBirthDate)),
+        ];
+
+        for (pnr, result) in cases {
+            assert_eq!(
+                Personnummer::new(pnr).unwrap().validate(),
+                result,
+                "{}",
+                pnr
+            );
+        }
+    }
+
+    #[test]
+    fn test_validation_report() {
+        let report = Personnummer::new("19900101-0018")
+            .unwrap()
+            .validation_report();
+
+        assert!(report.serial);
+        assert!(!report.checksum);
+        assert!(report.plausible);
+        assert!(!report.is_valid());
+    }
+}
@@ -0,0 +1,81 @@
+//! JavaScript bindings through `wasm-bindgen` so frontend validation shares the same logic as the
+//! backend. All functions take the personal identity number as a string and are strict, meaning
+//! that numbers with an invalid checksum are treated as invalid. Functions returning a value return
+//! `undefined` for invalid numbers, use `validationError()` to get the reason.
+//!
+//! Build the module with the `wasm` feature for the `wasm32-unknown-unknown` target as a `cdylib`
+//! and generate the JavaScript glue with `wasm-bindgen`.
+//!
+//! ```sh
+//! cargo rustc --lib --release --features wasm --target wasm32-unknown-unknown --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/personnummer.wasm
+//! ```
+
+use crate::{parse_valid, Locale};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Check if the input is a valid personal identity number.
+#[wasm_bindgen]
+pub fn validate(pnr: &str) -> bool {
+    parse_valid(pnr).is_ok()
+}
+
+/// Returns why the input isn't a valid personal identity number, or `undefined` if it's valid. The
+/// locale is `sv` for Swedish messages, any other value gives English messages.
+#[wasm_bindgen(js_name = validationError)]
+pub fn validation_error(pnr: &str, locale: &str) -> Option<String> {
+    let locale = match locale {
+        "sv" => Locale::Sv,
+        _ => Locale::En,
+    };
+
+    parse_valid(pnr).err().map(|e| e.message(locale))
+}
+
+/// Returns the long format, e.g. `19900101-0017`.
+#[wasm_bindgen(js_name = formatLong)]
+pub fn format_long(pnr: &str) -> Option<String> {
+    parse_valid(pnr).ok().map(|p| p.format().long())
+}
+
+/// Returns the short format, e.g. `900101-0017`.
+#[wasm_bindgen(js_name = formatShort)]
+pub fn format_short(pnr: &str) -> Option<String> {
+    parse_valid(pnr).ok().map(|p| p.format().short())
+}
+
+/// Returns the current age of the person.
+#[wasm_bindgen]
+pub fn age(pnr: &str) -> Option<u32> {
+    parse_valid(pnr).ok().map(|p| p.get_age() as u32)
+}
+
+/// Returns the gender of the person, `female` or `male`.
+#[wasm_bindgen]
+pub fn gender(pnr: &str) -> Option<String> {
+    parse_valid(pnr).ok().map(|p| p.gender().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bindings() {
+        assert!(validate("900101-0017"));
+        assert!(!validate("900101-0018"));
+
+        assert_eq!(validation_error("900101-0017", "sv"), None);
+        assert_eq!(
+            validation_error("900101-0018", "en").unwrap(),
+            "Invalid checksum, expected control digit 7 but got 8"
+        );
+        assert_eq!(format_long("9001010017").unwrap(), "19900101-0017");
+        assert_eq!(format_short("19900101-0017").unwrap(), "900101-0017");
+        assert_eq!(format_long("900101-0018"), None);
+        assert_eq!(gender("19900101-0017").unwrap(), "male");
+        assert!(age("19900101-0017").unwrap() >= 35);
+        assert_eq!(age("invalid"), None);
+    }
+}
@@ -0,0 +1,276 @@
+//! Runs the `personnummer` binary and checks the output and exit codes.
+
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_personnummer"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn run_with_stdin(args: &[&str], stdin: impl AsRef<[u8]>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_personnummer"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_ref())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_validate() {
+    let output = run(&["validate", "900101-0017", "19800161-3291"]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "900101-0017: valid\n19800161-3291: valid\n"
+    );
+
+    let output = run(&["validate", "900101-0017", "900101-0018"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "900101-0017: valid\n900101-0018: Invalid checksum, expected control digit 7 but got 8\n"
+    );
+
+    let output = run(&["--json", "validate", "900101-0018"]);
+
+    assert_eq!(
+        stdout(&output),
+        "{\"input\":\"900101-0018\",\"valid\":false,\"normalized\":null,\"error\":\"Invalid checksum, expected control digit 7 but got 8\"}\n"
+    );
+}
+
+#[test]
+fn test_validate_stdin() {
+    let output = run_with_stdin(&["validate", "--stdin"], "9001010017\n\n 800161-3291 \r\n");
+    let records = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+
+    assert!(output.status.success());
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["input"], "9001010017");
+    assert_eq!(records[0]["normalized"], "19900101-0017");
+    assert_eq!(records[0]["error"], serde_json::Value::Null);
+    assert_eq!(records[1]["input"], "800161-3291");
+
+    let output = run_with_stdin(&["validate", "--stdin"], "9001010017\ninvalid\n");
+    let last = stdout(&output).lines().last().unwrap().to_string();
+    let record: serde_json::Value = serde_json::from_str(&last).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(record["valid"], false);
+    assert_eq!(record["normalized"], serde_json::Value::Null);
+    assert_eq!(record["error"], "Invalid character 'i' at position 0");
+
+    let output = run_with_stdin(&["validate", "--stdin"], b"9001010017\n\xff\n800161-3291\n");
+    let records = stdout(&output)
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[1]["valid"], false);
+    assert_eq!(records[2]["valid"], true);
+
+    assert_eq!(
+        run(&["validate", "--stdin", "9001010017"]).status.code(),
+        Some(2)
+    );
+}
+
+#[test]
+fn test_format() {
+    let output = run(&["format", "9001010017"]);
+
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "19900101-0017\n");
+
+    let output = run(&["format", "--short", "--json", "19900101-0017"]);
+
+    assert_eq!(stdout(&output), "\"900101-0017\"\n");
+
+    let output = run(&["format", "900101-0018"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid checksum"));
+}
+
+#[test]
+fn test_info() {
+    let output = run(&["info", "--json", "19800161-3291"]);
+    let info: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(info["long"], "19800161-3291");
+    assert_eq!(info["birth_date"], "1980-01-01");
+    assert_eq!(info["gender"], "male");
+    assert_eq!(info["coordination"], true);
+}
+
+#[test]
+fn test_usage() {
+    assert_eq!(run(&[]).status.code(), Some(2));
+    assert_eq!(run(&["validate"]).status.code(), Some(2));
+}
+
+#[test]
+fn test_generate() {
+    let args = ["generate", "-n", "5", "--seed", "1", "--from", "1990-01-01"];
+    let output = run(&args);
+    let lines = stdout(&output);
+
+    assert!(output.status.success());
+    assert_eq!(lines.lines().count(), 5);
+    assert_eq!(lines, stdout(&run(&args)));
+
+    for line in lines.lines() {
+        assert!(run(&["validate", line]).status.success());
+    }
+
+    let output = run(&[
+        "generate",
+        "--json",
+        "-n",
+        "1",
+        "--gender",
+        "male",
+        "--coordination",
+    ]);
+    let info: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+
+    assert_eq!(info["gender"], "male");
+    assert_eq!(info["coordination"], true);
+
+    assert_eq!(
+        run(&["generate", "--from", "2100-01-01"]).status.code(),
+        Some(2)
+    );
+    assert_eq!(
+        run(&["generate", "--gender", "other"]).status.code(),
+        Some(2)
+    );
+
+    let output = run(&[
+        "generate",
+        "--from",
+        "4294967295-01-01",
+        "--to",
+        "0000-01-01",
+    ]);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid date '4294967295-01-01'"));
+
+    let args = [
+        "generate",
+        "-n",
+        "1",
+        "--from",
+        "0000-01-01",
+        "--to",
+        "0000-01-31",
+    ];
+    let output = run(&args);
+    let line = stdout(&output);
+
+    assert!(output.status.success());
+    assert!(line.starts_with("000001"), "{}", line);
+    assert!(run(&["validate", line.trim()]).status.success());
+}
+
+#[test]
+fn test_stats() {
+    let input = "900101-0017\n800161-3291\ninvalid\n\n19130401+2931\n";
+    let output = run_with_stdin(&["stats", "--json"], input);
+    let stats: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(stats["total"], 4);
+    assert_eq!(stats["valid"], 3);
+    assert_eq!(stats["invalid"], 1);
+    assert_eq!(stats["male"], 3);
+    assert_eq!(stats["coordination"], 1);
+    assert_eq!(stats["ages"][5]["range"], "80+");
+    assert_eq!(stats["ages"][5]["count"], 1);
+
+    let output = run_with_stdin(&["stats", "-"], input);
+
+    assert!(stdout(&output).starts_with("total                  4\n"));
+    assert_eq!(run(&["stats", "missing.txt"]).status.code(), Some(2));
+
+    let output = run_with_stdin(
+        &["stats", "--json"],
+        b"19900101-0017\n\xff\xfe\n800161-3291\n",
+    );
+    let stats: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(stats["total"], 3);
+    assert_eq!(stats["valid"], 2);
+    assert_eq!(stats["invalid"], 1);
+
+    let output = run_with_stdin(&["stats", "-", "-"], input);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("stdin can only be read once"));
+}
+
+#[test]
+fn test_redact() {
+    let input = "login 199001010017 ok\ninvalid 900101-0018\n";
+    let output = run_with_stdin(&["redact"], input);
+
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "login XXXXXXXXXXXX ok\ninvalid 900101-0018\n"
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "personnummer: redacted 1 personal identity numbers on 1 of 2 lines\n"
+    );
+
+    let args = [
+        "--json",
+        "redact",
+        "--style",
+        "pseudonym",
+        "--key",
+        "secret",
+    ];
+    let output = run_with_stdin(&args, input);
+    let summary: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+
+    assert!(!stdout(&output).contains("199001010017"));
+    assert_eq!(stdout(&output), stdout(&run_with_stdin(&args, input)));
+    assert_eq!(summary["redacted"], 1);
+
+    let output = run_with_stdin(&["redact", "--style", "pseudonym"], input);
+
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,54 @@
+//! Runs the shared test vectors from personnummer/meta. Set `PERSONNUMMER_META_LIST` to the path
+//! of a `list.json` from the meta repository to run the full list instead of the bundled one.
+
+use personnummer::{testdata, Personnummer};
+
+use std::{env, fs::File};
+
+fn cases() -> Vec<testdata::TestCase> {
+    match env::var("PERSONNUMMER_META_LIST") {
+        Ok(path) => testdata::from_reader(File::open(path).unwrap()).unwrap(),
+        Err(_) => testdata::cases(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    for tc in cases() {
+        for pnr in tc.formats() {
+            let valid = Personnummer::new(pnr).map(|p| p.valid()).unwrap_or(false);
+
+            assert_eq!(valid, tc.valid, "{}", pnr);
+        }
+    }
+}
+
+#[test]
+fn test_format() {
+    for tc in cases().into_iter().filter(|tc| tc.valid) {
+        for pnr in [&tc.long_format, &tc.separated_format, &tc.separated_long] {
+            assert_eq!(
+                Personnummer::new(pnr).unwrap().format().long(),
+                tc.separated_long,
+                "{}",
+                pnr
+            );
+        }
+    }
+}
+
+#[test]
+fn test_gender_and_coordination() {
+    for tc in cases().into_iter().filter(|tc| tc.valid) {
+        let p = Personnummer::new(&tc.separated_long).unwrap();
+
+        assert_eq!(p.is_male(), tc.is_male, "{}", tc.separated_long);
+        assert_eq!(p.is_female(), tc.is_female, "{}", tc.separated_long);
+        assert_eq!(
+            p.is_coordination_number(),
+            tc.is_coordination_number(),
+            "{}",
+            tc.separated_long
+        );
+    }
+}